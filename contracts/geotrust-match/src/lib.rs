@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractclient, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, Map, Vec, IntoVal, String,
-    token, Bytes,
+    contract, contracterror, contractclient, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, Map,
+    Vec, IntoVal, String, token, Bytes, xdr::ToXdr,
 };
 
 // Import GameHub contract interface
@@ -32,9 +32,20 @@ pub trait GameHub {
 pub enum SessionState {
     Waiting,
     Active,
+    Pending, // resolved, awaiting the challenge window before becoming final
     Ended,
 }
 
+/// An open challenge against a session's pending match outcome, filed during
+/// the post-`resolve_match` dispute window.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Challenge {
+    pub challenger: Address,
+    pub evidence: BytesN<32>,
+    pub resolved: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct LocationProof {
@@ -57,6 +68,36 @@ pub struct Session {
     pub p2_country: Option<u32>,
     pub p1_location_proof: Option<LocationProof>, // ZK proof for location (optional for MVP)
     pub p2_location_proof: Option<LocationProof>,
+    pub policy_version: u32, // policy_version this session was created under; see PolicySnapshot
+    pub resolved_ledger: Option<u32>, // ledger sequence resolve_match ran at, starts the challenge window
+    pub pending_matched: Option<bool>, // outcome computed by resolve_match, final once finalize_match runs
+    pub pending_winner: Option<Address>,
+    pub p1_encrypted_coords: Option<Bytes>, // fine-grained coordinates, encrypted under the session's document key
+    pub p2_encrypted_coords: Option<Bytes>,
+}
+
+/// A pending request to reveal a session's encrypted coordinates, open until
+/// enough registered key servers have submitted their decryption share.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RevealRequest {
+    pub requester: Address,
+    pub shares: Map<u32, Bytes>, // key server index -> submitted share
+    pub revealed: bool,
+}
+
+/// A frozen snapshot of the country-allow list and oracle quorum at a given
+/// `policy_version`. Sessions record the version they were created under so
+/// rotating the oracle committee or tightening country rules mid-flight never
+/// changes the rules an in-progress session is judged against.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PolicySnapshot {
+    pub allow_map: Map<u32, bool>,
+    pub region_allow: Map<u32, bool>,
+    pub country_region: Map<u32, u32>,
+    pub oracles: Vec<BytesN<32>>,
+    pub oracle_threshold: u32,
 }
 
 #[contracttype]
@@ -75,6 +116,29 @@ pub struct CountryInfo {
     pub created_at: u64,        // Timestamp when country was registered
 }
 
+/// Per-transaction and rolling-daily withdrawal caps for a country/asset
+/// pair. `max_per_tx`/`daily_max` are denominated in the asset's smallest
+/// unit, per `decimals` (the token's `decimals()` at the time the limit was
+/// configured).
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WithdrawalLimit {
+    pub max_per_tx: i128,
+    pub daily_max: i128,
+    pub decimals: u32,
+}
+
+/// A short-lived delegated signer an account owner has authorized to call
+/// `execute_payment_with_session` on their behalf, bounded in time and spend.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SessionKey {
+    pub expires_at: u64,
+    pub spend_cap: i128,
+    pub spent: i128,
+    pub nonce: u64,
+}
+
 #[contract]
 pub struct GeoTrustMatch;
 
@@ -87,9 +151,79 @@ impl GeoTrustMatch {
         env.storage()
             .instance()
             .set(&symbol_short!("DefAllow"), &default_allow_all);
+
+        // Seed policy_version 0 with empty allow/region lists and no oracle quorum
+        env.storage().instance().set(&symbol_short!("PolVer"), &0u32);
+        Self::store_policy_snapshot(&env, 0);
+
         Self::extend_instance_ttl(&env);
     }
 
+    /// Bump `policy_version` and snapshot the live policy state (country
+    /// allow list, region allow list, country->region map, oracle quorum)
+    /// under the new version. Called whenever any of those move, so
+    /// in-flight sessions keep judging joins against the snapshot recorded
+    /// at session creation.
+    fn bump_policy_version(env: &Env) -> u32 {
+        let current: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("PolVer"))
+            .unwrap_or(0);
+        let next = current.checked_add(1)
+            .unwrap_or_else(|| panic!("Policy version overflow"));
+        env.storage().instance().set(&symbol_short!("PolVer"), &next);
+        Self::store_policy_snapshot(env, next);
+        next
+    }
+
+    /// Snapshot the live policy maps under `version`
+    fn store_policy_snapshot(env: &Env, version: u32) {
+        let allow_map: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("AllowCnt"))
+            .unwrap_or(Map::new(env));
+        let region_allow: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("RgnAllow"))
+            .unwrap_or(Map::new(env));
+        let country_region: Map<u32, u32> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntRgn"))
+            .unwrap_or(Map::new(env));
+        let (oracles, oracle_threshold) = Self::current_oracle_set(env);
+
+        let snapshot = PolicySnapshot {
+            allow_map,
+            region_allow,
+            country_region,
+            oracles,
+            oracle_threshold,
+        };
+        let key = (symbol_short!("PolSnap"), version);
+        env.storage().persistent().set(&key, &snapshot);
+    }
+
+    /// Fetch the policy snapshot recorded for `version`
+    fn get_policy_snapshot(env: &Env, version: u32) -> PolicySnapshot {
+        let key = (symbol_short!("PolSnap"), version);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Policy snapshot missing"))
+    }
+
+    /// Current `policy_version`
+    pub fn get_policy_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("PolVer"))
+            .unwrap_or(0)
+    }
+
     /// Get admin for a specific country (returns country admin if set, otherwise main admin)
     fn get_admin_for_country(env: &Env, country: Option<u32>) -> Option<Address> {
         // If country is specified, check for country-specific admin
@@ -129,13 +263,42 @@ impl GeoTrustMatch {
         Self::extend_instance_ttl(&env);
     }
 
-    /// Set ZK verifier address (admin-only)
+    /// Set a single ZK verifier address (admin-only). Convenience wrapper
+    /// around `set_verifiers` for the degenerate 1-of-1 quorum.
     pub fn set_verifier(env: Env, verifier: Address) {
+        let verifiers = vec![&env, verifier];
+        Self::set_verifiers(env, verifiers, 1);
+    }
+
+    /// Set the M-of-N ZK verifier quorum (admin-only). A location proof is
+    /// accepted once `threshold` distinct verifiers in the set confirm it.
+    pub fn set_verifiers(env: Env, verifiers: Vec<Address>, threshold: u32) {
         Self::require_admin_auth(&env, None);
-        env.storage().instance().set(&symbol_short!("Verifier"), &verifier);
+
+        if verifiers.is_empty() || threshold == 0 || threshold > verifiers.len() {
+            panic!("Threshold must be between 1 and the verifier set size");
+        }
+
+        env.storage().instance().set(&symbol_short!("Verifiers"), &verifiers);
+        env.storage().instance().set(&symbol_short!("VerThr"), &threshold);
         Self::extend_instance_ttl(&env);
     }
 
+    /// Get the current ZK verifier quorum and its threshold
+    pub fn get_verifiers(env: Env) -> (Vec<Address>, u32) {
+        let verifiers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("Verifiers"))
+            .unwrap_or(vec![&env]);
+        let threshold: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("VerThr"))
+            .unwrap_or(0);
+        (verifiers, threshold)
+    }
+
     /// Set a new main admin (main admin-only)
     pub fn set_admin(env: Env, new_admin: Address) {
         Self::require_admin_auth(&env, None);
@@ -207,6 +370,182 @@ impl GeoTrustMatch {
         env.storage()
             .persistent()
             .set(&symbol_short!("AllowCnt"), &allow_map);
+
+        Self::bump_policy_version(&env);
+    }
+
+    /// Register the oracle quorum used to attest session joins (main admin-only)
+    /// Oracles are identified by their ed25519 public key. `threshold` must be
+    /// between 1 and `oracles.len()`; pass an empty set and threshold 0 to
+    /// disable attestation requirements entirely.
+    pub fn set_oracle_set(env: Env, oracles: Vec<BytesN<32>>, threshold: u32) {
+        Self::require_admin_auth(&env, None);
+
+        if oracles.is_empty() {
+            if threshold != 0 {
+                panic!("Threshold must be zero when oracle set is empty");
+            }
+        } else if threshold == 0 || threshold > oracles.len() {
+            panic!("Threshold must be between 1 and the oracle set size");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("Oracles"), &oracles);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("OracThr"), &threshold);
+
+        Self::bump_policy_version(&env);
+    }
+
+    /// Set region (ISO-3166-1 regional/continent grouping) allowed status
+    /// (main admin-only). Acts as a fallback layer below explicit country
+    /// overrides and above the global default in `get_effective_policy`.
+    pub fn set_region_allowed(env: Env, region_code: u32, allowed: bool) {
+        Self::require_admin_auth(&env, None);
+
+        let mut region_allow: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("RgnAllow"))
+            .unwrap_or(Map::new(&env));
+
+        // Store the explicit value: a `false` entry blocks the whole region,
+        // which `get_effective_policy` distinguishes from "no region policy"
+        region_allow.set(region_code, allowed);
+
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("RgnAllow"), &region_allow);
+
+        Self::bump_policy_version(&env);
+    }
+
+    /// Assign a country to a region/continent grouping (country admin or main
+    /// admin), so
+    /// `get_effective_policy` can fall back to that region's policy.
+    pub fn set_country_region(env: Env, country: u32, region_code: u32) {
+        Self::require_admin_auth(&env, Some(country));
+
+        let mut country_region: Map<u32, u32> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntRgn"))
+            .unwrap_or(Map::new(&env));
+
+        country_region.set(country, region_code);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("CntRgn"), &country_region);
+
+        Self::bump_policy_version(&env);
+    }
+
+    /// Resolve the effective policy for `country` using the live policy
+    /// state: explicit country override (deny, then allow), then the
+    /// country's region policy (if assigned), then the global default.
+    pub fn get_effective_policy(env: Env, country: u32) -> bool {
+        let deny_map: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("DenyCnt"))
+            .unwrap_or(Map::new(&env));
+        if deny_map.try_get(country).unwrap_or_default().is_some() {
+            return false;
+        }
+
+        let allow_map: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("AllowCnt"))
+            .unwrap_or(Map::new(&env));
+        if allow_map.try_get(country).unwrap_or_default().is_some() {
+            return true;
+        }
+
+        let country_region: Map<u32, u32> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntRgn"))
+            .unwrap_or(Map::new(&env));
+        if let Some(region_code) = country_region.get(country) {
+            let region_allow: Map<u32, bool> = env
+                .storage()
+                .persistent()
+                .get(&symbol_short!("RgnAllow"))
+                .unwrap_or(Map::new(&env));
+            if let Some(allowed) = region_allow.get(region_code) {
+                return allowed;
+            }
+        }
+
+        env.storage()
+            .instance()
+            .get(&symbol_short!("DefAllow"))
+            .unwrap_or(false)
+    }
+
+    /// Get the registered oracle set and required threshold
+    pub fn get_oracle_set(env: Env) -> (Vec<BytesN<32>>, u32) {
+        Self::current_oracle_set(&env)
+    }
+
+    fn current_oracle_set(env: &Env) -> (Vec<BytesN<32>>, u32) {
+        let oracles: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("Oracles"))
+            .unwrap_or(vec![env]);
+        let threshold: u32 = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("OracThr"))
+            .unwrap_or(0);
+        (oracles, threshold)
+    }
+
+    /// Verify that enough distinct registered oracles signed the join claim
+    /// `(player, session_id, country, asset_tag)`, judged against the oracle
+    /// set frozen in `snapshot` (the session's `policy_version`). No-op when
+    /// that snapshot carries no oracle quorum, so the feature is opt-in.
+    fn verify_oracle_attestations(
+        env: &Env,
+        snapshot: &PolicySnapshot,
+        player: &Address,
+        session_id: u32,
+        country: u32,
+        asset_tag: &BytesN<32>,
+        attestations: &Vec<(BytesN<32>, BytesN<64>)>,
+    ) {
+        let oracles = &snapshot.oracles;
+        let threshold = snapshot.oracle_threshold;
+
+        if threshold == 0 {
+            return;
+        }
+
+        let message = (player.clone(), session_id, country, asset_tag.clone()).to_xdr(env);
+
+        let mut seen: Vec<BytesN<32>> = vec![env];
+        let mut valid_count: u32 = 0;
+
+        for (oracle_pubkey, sig) in attestations.iter() {
+            if !oracles.iter().any(|registered| registered == oracle_pubkey) {
+                continue;
+            }
+            if seen.iter().any(|seen_key| seen_key == oracle_pubkey) {
+                continue;
+            }
+            env.crypto().ed25519_verify(&oracle_pubkey, &message, &sig);
+            seen.push_back(oracle_pubkey.clone());
+            valid_count = valid_count.checked_add(1)
+                .unwrap_or_else(|| panic!("Attestation count overflow"));
+        }
+
+        if valid_count < threshold {
+            panic!("Insufficient oracle attestations");
+        }
     }
 
     /// Set default allow all policy (main admin-only)
@@ -288,7 +627,9 @@ impl GeoTrustMatch {
     }
 
     /// Create a new session
-    pub fn create_session(env: Env) -> u32 {
+    pub fn create_session(env: Env, creator: Address) -> u32 {
+        creator.require_auth();
+
         let current_id = env.storage().instance().get(&symbol_short!("NextSess"))
             .unwrap_or(0u32);
         let session_id = current_id.checked_add(1)
@@ -296,6 +637,12 @@ impl GeoTrustMatch {
         env.storage().instance().set(&symbol_short!("NextSess"), &session_id);
         Self::extend_instance_ttl(&env);
 
+        let policy_version: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("PolVer"))
+            .unwrap_or(0);
+
         let session = Session {
             player1: None,
             player2: None,
@@ -309,6 +656,12 @@ impl GeoTrustMatch {
             p2_country: None,
             p1_location_proof: None,
             p2_location_proof: None,
+            policy_version,
+            resolved_ledger: None,
+            pending_matched: None,
+            pending_winner: None,
+            p1_encrypted_coords: None,
+            p2_encrypted_coords: None,
         };
 
         let key = (symbol_short!("Session"), session_id);
@@ -317,6 +670,9 @@ impl GeoTrustMatch {
         // threshold must be <= extend_to, so we use threshold=100000, extend_to=100001
         env.storage().temporary().extend_ttl(&key, 100000, 100001);
 
+        env.events()
+            .publish((symbol_short!("SessNew"), session_id), creator);
+
         session_id
     }
 
@@ -329,14 +685,37 @@ impl GeoTrustMatch {
         asset_tag: BytesN<32>,
         country: u32,
         location_proof: Option<LocationProof>,
+        oracle_attestations: Vec<(BytesN<32>, BytesN<64>)>,
+        encrypted_coords: Bytes,
     ) {
         caller.require_auth();
 
-        // Check country policy
-        if !Self::is_country_allowed_internal(&env, country) {
+        let key = (symbol_short!("Session"), session_id);
+        let mut session: Session = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Session not found"));
+
+        // Judge this join against the policy snapshot frozen when the session
+        // was created, so a later policy rotation can't disturb it in-flight.
+        let snapshot = Self::get_policy_snapshot(&env, session.policy_version);
+
+        if !Self::is_country_allowed_versioned(&env, &snapshot, country) {
             panic!("Country not allowed");
         }
 
+        // Check oracle attestation threshold, if an oracle set has been configured
+        Self::verify_oracle_attestations(
+            &env,
+            &snapshot,
+            &caller,
+            session_id,
+            country,
+            &asset_tag,
+            &oracle_attestations,
+        );
+
         // Verify location proof if provided
         if let Some(ref proof) = location_proof {
             // Verify public inputs match cell_id
@@ -349,27 +728,31 @@ impl GeoTrustMatch {
                 panic!("Location proof public inputs mismatch");
             }
             
-            // Verify proof via verifier contract if configured
-            if let Some(verifier_addr) = env.storage().instance().get::<_, Address>(&symbol_short!("Verifier")) {
-                // Call verifier contract to verify the proof
-                let verify_result: bool = env.invoke_contract(
-                    &verifier_addr,
-                    &symbol_short!("verify"),
-                    soroban_sdk::vec![&env, proof.clone().into_val(&env), cell_id.into_val(&env)],
-                );
-                if !verify_result {
+            // Verify proof via the ZK verifier quorum, if configured. The
+            // proof is accepted once `threshold` distinct verifiers confirm
+            // it; we short-circuit as soon as quorum is reached.
+            let (verifiers, threshold) = Self::get_verifiers(env.clone());
+            if !verifiers.is_empty() {
+                let mut confirmations = 0u32;
+                for verifier_addr in verifiers.iter() {
+                    let verify_result: bool = env.invoke_contract(
+                        &verifier_addr,
+                        &symbol_short!("verify"),
+                        soroban_sdk::vec![&env, proof.clone().into_val(&env), cell_id.into_val(&env)],
+                    );
+                    if verify_result {
+                        confirmations += 1;
+                        if confirmations >= threshold {
+                            break;
+                        }
+                    }
+                }
+                if confirmations < threshold {
                     panic!("Location proof verification failed");
                 }
             }
         }
 
-        let key = (symbol_short!("Session"), session_id);
-        let mut session: Session = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic!("Session not found"));
-
         if session.state != SessionState::Waiting {
             panic!("Session not in waiting state");
         }
@@ -380,6 +763,7 @@ impl GeoTrustMatch {
             session.p1_asset_tag = Some(asset_tag);
             session.p1_country = Some(country);
             session.p1_location_proof = location_proof;
+            session.p1_encrypted_coords = Some(encrypted_coords);
         } else if session.player2.is_none() {
             if session.player1 == Some(caller.clone()) {
                 panic!("Player already in session");
@@ -389,6 +773,7 @@ impl GeoTrustMatch {
             session.p2_asset_tag = Some(asset_tag);
             session.p2_country = Some(country);
             session.p2_location_proof = location_proof;
+            session.p2_encrypted_coords = Some(encrypted_coords);
             session.state = SessionState::Active;
 
             // Call Game Hub start_game if configured
@@ -420,6 +805,9 @@ impl GeoTrustMatch {
             panic!("Session is full");
         }
 
+        env.events()
+            .publish((symbol_short!("PlyrJoin"), session_id), (caller, country));
+
         env.storage().temporary().set(&key, &session);
         // Extend TTL: signature is extend_ttl(key, threshold, extend_to)
         // threshold must be <= extend_to, so we use threshold=100000, extend_to=100001
@@ -439,6 +827,10 @@ impl GeoTrustMatch {
             panic!("Session not active");
         }
 
+        // Confirm the policy snapshot this session was created under is
+        // still around to audit against before finalizing the match.
+        Self::get_policy_snapshot(&env, session.policy_version);
+
         let player1 = session.player1.clone()
             .unwrap_or_else(|| panic!("Player 1 not set"));
         let player2 = session.player2.clone()
@@ -463,17 +855,166 @@ impl GeoTrustMatch {
             Some(player2.clone()) // Second player wins if no match
         };
 
+        // Move to Pending rather than Ended: the outcome is provisional until
+        // the challenge window elapses with no unresolved dispute.
+        session.state = SessionState::Pending;
+        session.resolved_ledger = Some(env.ledger().sequence());
+        session.pending_matched = Some(matched);
+        session.pending_winner = winner.clone();
+
+        env.storage().temporary().set(&key, &session);
+        // Extend TTL: signature is extend_ttl(key, threshold, extend_to)
+        // threshold must be <= extend_to, so we use threshold=100000, extend_to=100001
+        env.storage().temporary().extend_ttl(&key, 100000, 100001);
+
+        MatchResult { matched, winner }
+    }
+
+    /// Configurable challenge window, in ledgers, that `resolve_match`'s
+    /// outcome sits in `Pending` before it can be finalized (main admin-only)
+    pub fn set_challenge_window(env: Env, ledgers: u32) {
+        Self::require_admin_auth(&env, None);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ChalWin"), &ledgers);
+        Self::extend_instance_ttl(&env);
+    }
+
+    /// Current challenge window, in ledgers. Defaults to 100 (~8 minutes).
+    pub fn get_challenge_window(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("ChalWin"))
+            .unwrap_or(100)
+    }
+
+    /// File a challenge with supporting evidence against a `Pending` match,
+    /// while the challenge window is still open. Only one unresolved
+    /// challenge may be outstanding per session at a time.
+    pub fn challenge_match(env: Env, session_id: u32, challenger: Address, evidence: BytesN<32>) {
+        challenger.require_auth();
+
+        let key = (symbol_short!("Session"), session_id);
+        let session: Session = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Session not found"));
+
+        if session.state != SessionState::Pending {
+            panic!("Session not pending finalization");
+        }
+
+        if session.player1 != Some(challenger.clone()) && session.player2 != Some(challenger.clone()) {
+            panic!("Only a session player may file a challenge");
+        }
+
+        let resolved_ledger = session.resolved_ledger
+            .unwrap_or_else(|| panic!("Session missing resolution ledger"));
+        let elapsed = env.ledger().sequence().checked_sub(resolved_ledger)
+            .unwrap_or_else(|| panic!("Ledger sequence underflow"));
+        if elapsed >= Self::get_challenge_window(env.clone()) {
+            panic!("Challenge window has closed");
+        }
+
+        let challenge_key = (symbol_short!("Chlnge"), session_id);
+        if let Some(existing) = env.storage().temporary().get::<_, Challenge>(&challenge_key) {
+            if !existing.resolved {
+                panic!("Session already challenged");
+            }
+        }
+
+        let challenge = Challenge {
+            challenger: challenger.clone(),
+            evidence,
+            resolved: false,
+        };
+        env.storage().temporary().set(&challenge_key, &challenge);
+        env.storage().temporary().extend_ttl(&challenge_key, 100000, 100001);
+
+        env.events()
+            .publish((symbol_short!("Challenge"), session_id), challenger);
+    }
+
+    /// Adjudicate the outstanding challenge on a session (main admin-only).
+    /// If the challenger is upheld, the pending outcome is flipped in their
+    /// favor; otherwise the original `resolve_match` outcome stands.
+    pub fn adjudicate_challenge(env: Env, session_id: u32, uphold_challenger: bool) {
+        Self::require_admin_auth(&env, None);
+
+        let challenge_key = (symbol_short!("Chlnge"), session_id);
+        let mut challenge: Challenge = env
+            .storage()
+            .temporary()
+            .get(&challenge_key)
+            .unwrap_or_else(|| panic!("No challenge filed for session"));
+
+        if challenge.resolved {
+            panic!("Challenge already resolved");
+        }
+        challenge.resolved = true;
+        env.storage().temporary().set(&challenge_key, &challenge);
+
+        if uphold_challenger {
+            let key = (symbol_short!("Session"), session_id);
+            let mut session: Session = env
+                .storage()
+                .temporary()
+                .get(&key)
+                .unwrap_or_else(|| panic!("Session not found"));
+
+            session.pending_matched = Some(!session.pending_matched.unwrap_or(false));
+            session.pending_winner = Some(challenge.challenger.clone());
+            env.storage().temporary().set(&key, &session);
+        }
+    }
+
+    /// Finalize a session's match outcome once the challenge window has
+    /// elapsed with no unresolved challenge, running the deferred Game Hub
+    /// notification and emitting `match_resolved`.
+    pub fn finalize_match(env: Env, session_id: u32) -> MatchResult {
+        let key = (symbol_short!("Session"), session_id);
+        let mut session: Session = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Session not found"));
+
+        if session.state != SessionState::Pending {
+            panic!("Session not pending finalization");
+        }
+
+        let resolved_ledger = session.resolved_ledger
+            .unwrap_or_else(|| panic!("Session missing resolution ledger"));
+        let elapsed = env.ledger().sequence().checked_sub(resolved_ledger)
+            .unwrap_or_else(|| panic!("Ledger sequence underflow"));
+        if elapsed < Self::get_challenge_window(env.clone()) {
+            panic!("Challenge window still open");
+        }
+
+        let challenge_key = (symbol_short!("Chlnge"), session_id);
+        if let Some(challenge) = env.storage().temporary().get::<_, Challenge>(&challenge_key) {
+            if !challenge.resolved {
+                panic!("Unresolved challenge pending");
+            }
+        }
+
+        let matched = session.pending_matched
+            .unwrap_or_else(|| panic!("Session missing pending outcome"));
+        let winner = session.pending_winner.clone();
+
         session.state = SessionState::Ended;
 
         // Call Game Hub end_game if configured
         // Game Hub interface: end_game(session_id: u32, player1_won: bool)
         if let Some(game_hub_addr) = env.storage().instance().get::<_, Address>(&symbol_short!("GameHub")) {
-            // Determine if player1 won (matched means player1 wins)
-            let player1_won = matched;
-            
+            // Determine if player1 won (matched means player1 wins, unless a
+            // challenge flipped the outcome to player2)
+            let player1_won = session.player1 == winner;
+
             // Create GameHub client
             let game_hub = GameHubClient::new(&env, &game_hub_addr);
-            
+
             // Call GameHub to end the session
             // This will create a transaction visible on Stellar Expert
             game_hub.end_game(&session_id, &player1_won);
@@ -487,6 +1028,10 @@ impl GeoTrustMatch {
         // threshold must be <= extend_to, so we use threshold=100000, extend_to=100001
         env.storage().temporary().extend_ttl(&key, 100000, 100001);
 
+        let asset_tag = session.p1_asset_tag.clone();
+        env.events()
+            .publish((symbol_short!("MatchRslv"), session_id), (matched, asset_tag));
+
         MatchResult { matched, winner }
     }
 
@@ -496,16 +1041,78 @@ impl GeoTrustMatch {
         env.storage().temporary().get(&key)
     }
 
-    /// Internal function to check if country is allowed
-    fn is_country_allowed_internal(env: &Env, country: u32) -> bool {
-        // Check deny list first (use try_get to avoid panics)
-        let deny_map: Map<u32, bool> = env
-            .storage()
-            .persistent()
-            .get(&symbol_short!("DenyCnt"))
-            .unwrap_or(Map::new(env));
-
-        if deny_map.try_get(country).unwrap_or_default().is_some() {
+    /// Set the session abandonment timeout, in ledgers. Defaults to 17280
+    /// (~1 day). Admin-only.
+    pub fn set_session_timeout(env: Env, ledgers: u32) {
+        Self::require_admin_auth(&env, None);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SessTO"), &ledgers);
+        Self::extend_instance_ttl(&env);
+    }
+
+    /// Current session abandonment timeout, in ledgers
+    pub fn get_session_timeout(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("SessTO"))
+            .unwrap_or(17_280)
+    }
+
+    /// Cancel an abandoned session, reclaiming it for reuse. Player1 may
+    /// cancel once `session_timeout` ledgers have passed since creation; the
+    /// main admin can cancel at any time. Only a session still `Waiting` for
+    /// players is cancellable; an `Active`, `Pending` or `Ended` one is not.
+    pub fn cancel_session(env: Env, session_id: u32, caller: Address) {
+        caller.require_auth();
+
+        let key = (symbol_short!("Session"), session_id);
+        let session: Session = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Session not found"));
+
+        if session.state != SessionState::Waiting {
+            panic!("Session is no longer waiting for players");
+        }
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("Admin"))
+            .unwrap_or_else(|| panic!("Admin not set"));
+
+        let is_admin = caller == admin;
+        let is_player1 = session.player1 == Some(caller.clone());
+        if !is_admin && !is_player1 {
+            panic!("Only player1 or the admin can cancel a session");
+        }
+
+        if !is_admin {
+            let elapsed = env.ledger().sequence().checked_sub(session.created_ledger)
+                .unwrap_or_else(|| panic!("Ledger sequence underflow"));
+            if elapsed < Self::get_session_timeout(env.clone()) {
+                panic!("Session has not timed out yet");
+            }
+        }
+
+        env.storage().temporary().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("SessCncl"), session_id), caller);
+    }
+
+    /// Internal function to check if country is allowed
+    fn is_country_allowed_internal(env: &Env, country: u32) -> bool {
+        // Check deny list first (use try_get to avoid panics)
+        let deny_map: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("DenyCnt"))
+            .unwrap_or(Map::new(env));
+
+        if deny_map.try_get(country).unwrap_or_default().is_some() {
             return false;
         }
 
@@ -527,6 +1134,38 @@ impl GeoTrustMatch {
             .unwrap_or(false)
     }
 
+    /// Same precedence as `get_effective_policy` (deny, allow, region, global
+    /// default), but the allow list and region maps are taken from
+    /// `snapshot` rather than live storage, so they reflect the rules in
+    /// force when the session was created. The deny list and global default
+    /// are not versioned and are always read live.
+    fn is_country_allowed_versioned(env: &Env, snapshot: &PolicySnapshot, country: u32) -> bool {
+        let deny_map: Map<u32, bool> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("DenyCnt"))
+            .unwrap_or(Map::new(env));
+
+        if deny_map.try_get(country).unwrap_or_default().is_some() {
+            return false;
+        }
+
+        if snapshot.allow_map.try_get(country).unwrap_or_default().is_some() {
+            return true;
+        }
+
+        if let Some(region_code) = snapshot.country_region.get(country) {
+            if let Some(allowed) = snapshot.region_allow.get(region_code) {
+                return allowed;
+            }
+        }
+
+        env.storage()
+            .instance()
+            .get(&symbol_short!("DefAllow"))
+            .unwrap_or(false)
+    }
+
     // ========== Country Vault Functions ==========
 
     /// Validate country code (must be 2 uppercase letters, registered, and enabled)
@@ -626,6 +1265,185 @@ impl GeoTrustMatch {
         country_registry.get(country_code)
     }
 
+    // ========== WebAuthn / P-256 Passkey Verification ==========
+
+    /// Register a user's secp256r1/P-256 passkey public key (user-only).
+    /// Required before `deposit`/`withdraw` will accept that user's
+    /// WebAuthn assertions.
+    pub fn register_webauthn_key(env: Env, user_address: Address, public_key: BytesN<65>) {
+        user_address.require_auth();
+
+        let mut keys: Map<Address, BytesN<65>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("P256Keys"))
+            .unwrap_or(Map::new(&env));
+
+        keys.set(user_address, public_key);
+        env.storage().persistent().set(&symbol_short!("P256Keys"), &keys);
+    }
+
+    /// Find the byte offset of `needle` in `haystack`, or `None`
+    fn find_bytes(haystack: &Bytes, needle: &[u8]) -> Option<u32> {
+        let h_len = haystack.len();
+        let n_len = needle.len() as u32;
+        if n_len == 0 || n_len > h_len {
+            return None;
+        }
+        let mut i = 0u32;
+        while i + n_len <= h_len {
+            let mut matched = true;
+            let mut j = 0u32;
+            while j < n_len {
+                if haystack.get(i + j).unwrap_or(0) != needle[j as usize] {
+                    matched = false;
+                    break;
+                }
+                j += 1;
+            }
+            if matched {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Extract the JSON string value following `"<key>":"` in `json`, up to
+    /// (not including) the closing quote. Used to pull the `challenge` field
+    /// out of a WebAuthn `client_data_json` without a full JSON parser.
+    fn extract_json_string(env: &Env, json: &Bytes, key_marker: &[u8]) -> Bytes {
+        let start = Self::find_bytes(json, key_marker)
+            .unwrap_or_else(|| panic!("client_data missing expected field"));
+        let value_start = start + key_marker.len() as u32;
+        let len = json.len();
+        let mut end = value_start;
+        while end < len && json.get(end).unwrap_or(b'"') != b'"' {
+            end += 1;
+        }
+
+        let mut out = Bytes::new(env);
+        let mut i = value_start;
+        while i < end {
+            out.push_back(json.get(i).unwrap_or(0));
+            i += 1;
+        }
+        out
+    }
+
+    /// Base64url-encode (no padding) `data`, matching the encoding WebAuthn
+    /// clients use for the `challenge` field of `client_data_json`.
+    fn base64url_encode(env: &Env, data: &Bytes) -> Bytes {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+        let mut out = Bytes::new(env);
+        let len = data.len();
+        let mut i = 0u32;
+        while i + 3 <= len {
+            let b0 = data.get(i).unwrap_or(0);
+            let b1 = data.get(i + 1).unwrap_or(0);
+            let b2 = data.get(i + 2).unwrap_or(0);
+            out.push_back(ALPHABET[(b0 >> 2) as usize]);
+            out.push_back(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize]);
+            out.push_back(ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize]);
+            out.push_back(ALPHABET[(b2 & 0x3f) as usize]);
+            i += 3;
+        }
+
+        let remainder = len - i;
+        if remainder == 1 {
+            let b0 = data.get(i).unwrap_or(0);
+            out.push_back(ALPHABET[(b0 >> 2) as usize]);
+            out.push_back(ALPHABET[((b0 & 0x03) << 4) as usize]);
+        } else if remainder == 2 {
+            let b0 = data.get(i).unwrap_or(0);
+            let b1 = data.get(i + 1).unwrap_or(0);
+            out.push_back(ALPHABET[(b0 >> 2) as usize]);
+            out.push_back(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize]);
+            out.push_back(ALPHABET[((b1 & 0x0f) << 2) as usize]);
+        }
+
+        out
+    }
+
+    /// Verify a WebAuthn assertion for `user_address`: confirm
+    /// `client_data_json` is a `"webauthn.get"` ceremony whose challenge
+    /// matches `signature_payload`, then verify `webauthn_signature` over
+    /// `sha256(authenticator_data || sha256(client_data_json))` against the
+    /// user's registered secp256r1 public key. Panics on any mismatch.
+    fn verify_webauthn(
+        env: &Env,
+        user_address: &Address,
+        signature_payload: &Bytes,
+        webauthn_signature: &BytesN<64>,
+        authenticator_data: &Bytes,
+        client_data: &Bytes,
+    ) {
+        let public_key: BytesN<65> = env
+            .storage()
+            .persistent()
+            .get::<_, Map<Address, BytesN<65>>>(&symbol_short!("P256Keys"))
+            .unwrap_or(Map::new(env))
+            .get(user_address.clone())
+            .unwrap_or_else(|| panic!("No WebAuthn credential registered for user"));
+
+        if Self::find_bytes(client_data, b"\"type\":\"webauthn.get\"").is_none() {
+            panic!("client_data is not a webauthn.get assertion");
+        }
+
+        let challenge = Self::extract_json_string(env, client_data, b"\"challenge\":\"");
+        let expected_challenge = Self::base64url_encode(env, signature_payload);
+        if challenge != expected_challenge {
+            panic!("client_data challenge does not match signature payload");
+        }
+
+        let client_data_hash = env.crypto().sha256(client_data);
+        let mut message = authenticator_data.clone();
+        message.append(&Bytes::from_array(env, &client_data_hash.to_array()));
+        let digest = env.crypto().sha256(&message);
+        let digest_bytes = BytesN::from_array(env, &digest.to_array());
+
+        env.crypto()
+            .secp256r1_verify(&public_key, &digest_bytes, webauthn_signature);
+    }
+
+    /// Derive the WebAuthn challenge `withdraw` expects to be signed, as the
+    /// sha256 of the XDR encoding of `(user_address, country_code, asset,
+    /// amount)`. Binding the challenge to these fields on-chain means a
+    /// signature captured off one withdrawal can't be replayed to move a
+    /// different amount, or from a different vault.
+    fn compute_withdrawal_payload(env: &Env, user_address: &Address, country_code: &String, asset: &Address, amount: i128) -> Bytes {
+        let message = (user_address.clone(), country_code.clone(), asset.clone(), amount).to_xdr(env);
+        let digest = env.crypto().sha256(&message);
+        Bytes::from_array(env, &digest.to_array())
+    }
+
+    /// Adjust the aggregate vault total held for `country_code`/`asset` by
+    /// `delta`, independent of any single user's balance. Backs
+    /// `get_country_vault_total`.
+    fn adjust_country_vault_total(env: &Env, country_code: &String, asset: &Address, delta: i128) {
+        let mut vault_totals: Map<String, Map<Address, i128>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntVault"))
+            .unwrap_or(Map::new(env));
+
+        let mut asset_totals: Map<Address, i128> = vault_totals
+            .get(country_code.clone())
+            .unwrap_or(Map::new(env));
+
+        let current = asset_totals.get(asset.clone()).unwrap_or(0);
+        let updated = if delta >= 0 {
+            current.checked_add(delta).unwrap_or_else(|| panic!("Vault total overflow"))
+        } else {
+            current.checked_sub(-delta).unwrap_or_else(|| panic!("Vault total underflow"))
+        };
+        asset_totals.set(asset.clone(), updated);
+        vault_totals.set(country_code.clone(), asset_totals);
+        env.storage().persistent().set(&symbol_short!("CntVault"), &vault_totals);
+    }
+
     /// Deposit tokens to country-specific vault
     /// Following the same pattern as XYZ-Wallet: verify auth, check balance, transfer, update storage
     pub fn deposit(
@@ -634,15 +1452,24 @@ impl GeoTrustMatch {
         country_code: String,
         asset: Address,
         amount: i128,
-        _signature_payload: Bytes, // WebAuthn signature payload (can be verified externally)
-        _webauthn_signature: Bytes, // WebAuthn signature (for future verification)
-        _webauthn_authenticator_data: Bytes, // WebAuthn authenticator data
-        _webauthn_client_data: Bytes, // WebAuthn client data JSON
+        signature_payload: Bytes, // WebAuthn signature payload (the challenge)
+        webauthn_signature: BytesN<64>, // raw (r || s) secp256r1 signature
+        webauthn_authenticator_data: Bytes,
+        webauthn_client_data: Bytes, // WebAuthn client_data_json
     ) -> bool {
         if amount <= 0 {
             panic!("Amount must be positive");
         }
 
+        Self::verify_webauthn(
+            &env,
+            &user_address,
+            &signature_payload,
+            &webauthn_signature,
+            &webauthn_authenticator_data,
+            &webauthn_client_data,
+        );
+
         // Validate country code (must be registered and enabled)
         // Check this early to fail fast with clear error
         if country_code.len() != 2 {
@@ -711,6 +1538,283 @@ impl GeoTrustMatch {
         user_balances.set(country_code.clone(), country_balances);
         balances_map.set(user_address.clone(), user_balances);
         env.storage().persistent().set(&symbol_short!("Balances"), &balances_map);
+        Self::adjust_country_vault_total(&env, &country_code, &asset, amount);
+
+        true
+    }
+
+    /// Withdraw tokens from a country-specific vault, authenticated by the
+    /// same WebAuthn passkey assertion as `deposit`. The signed challenge is
+    /// derived on-chain from `(user_address, country_code, asset, amount)`
+    /// (see `compute_withdrawal_payload`) rather than accepted as a caller
+    /// argument, so a signature captured off a valid call can't be replayed
+    /// against a different amount; `user_address.require_auth()` closes the
+    /// gap this left open relative to `deposit`/`execute_payment`.
+    pub fn withdraw(
+        env: Env,
+        user_address: Address,
+        country_code: String,
+        asset: Address,
+        amount: i128,
+        webauthn_signature: BytesN<64>,
+        webauthn_authenticator_data: Bytes,
+        webauthn_client_data: Bytes,
+    ) -> bool {
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        if country_code.len() != 2 {
+            panic!("Country code must be exactly 2 characters");
+        }
+
+        let country_registry: Map<String, CountryInfo> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntReg"))
+            .unwrap_or(Map::new(&env));
+
+        let country_info = match country_registry.get(country_code.clone()) {
+            Some(info) => info,
+            None => panic!("Country not registered"),
+        };
+
+        if !country_info.enabled {
+            panic!("Country vault is disabled");
+        }
+
+        let signature_payload = Self::compute_withdrawal_payload(&env, &user_address, &country_code, &asset, amount);
+        Self::verify_webauthn(
+            &env,
+            &user_address,
+            &signature_payload,
+            &webauthn_signature,
+            &webauthn_authenticator_data,
+            &webauthn_client_data,
+        );
+
+        // Require authorization from the user too, for parity with
+        // `deposit`/`execute_payment` - the WebAuthn check alone shouldn't be
+        // the only gate on moving funds out of the vault
+        user_address.require_auth();
+
+        let mut balances_map: Map<Address, Map<String, Map<Address, i128>>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("Balances"))
+            .unwrap_or(Map::new(&env));
+
+        let mut user_balances: Map<String, Map<Address, i128>> = balances_map
+            .get(user_address.clone())
+            .unwrap_or(Map::new(&env));
+
+        let mut country_balances: Map<Address, i128> = user_balances
+            .get(country_code.clone())
+            .unwrap_or(Map::new(&env));
+
+        let current_balance = country_balances.get(asset.clone()).unwrap_or(0);
+        if current_balance < amount {
+            panic!("Insufficient vault balance");
+        }
+        let new_balance = current_balance.checked_sub(amount)
+            .unwrap_or_else(|| panic!("Balance underflow"));
+        country_balances.set(asset.clone(), new_balance);
+        user_balances.set(country_code.clone(), country_balances);
+        balances_map.set(user_address.clone(), user_balances);
+        env.storage().persistent().set(&symbol_short!("Balances"), &balances_map);
+        Self::adjust_country_vault_total(&env, &country_code, &asset, -amount);
+
+        let token_client = token::Client::new(&env, &asset);
+        let contract_address = env.current_contract_address();
+        token_client.transfer(&contract_address, &user_address, &amount);
+
+        true
+    }
+
+    /// Register a short-lived session key `owner` delegates signing
+    /// authority to. A client can sign subsequent `execute_payment_with_session`
+    /// calls with `session_pubkey`'s matching ed25519 key instead of producing
+    /// a full account authorization each time, bounded by `expires_at` and
+    /// `spend_cap`.
+    pub fn register_session_key(env: Env, owner: Address, session_pubkey: BytesN<32>, expires_at: u64, spend_cap: i128) {
+        owner.require_auth();
+
+        if spend_cap <= 0 {
+            panic!("Spend cap must be positive");
+        }
+
+        let session_key = SessionKey {
+            expires_at,
+            spend_cap,
+            spent: 0,
+            nonce: 0,
+        };
+
+        let mut session_keys: Map<(Address, BytesN<32>), SessionKey> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SessKeys"))
+            .unwrap_or(Map::new(&env));
+        session_keys.set((owner, session_pubkey), session_key);
+        env.storage().persistent().set(&symbol_short!("SessKeys"), &session_keys);
+    }
+
+    /// Revoke a previously registered session key
+    pub fn revoke_session_key(env: Env, owner: Address, session_pubkey: BytesN<32>) {
+        owner.require_auth();
+
+        let mut session_keys: Map<(Address, BytesN<32>), SessionKey> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SessKeys"))
+            .unwrap_or(Map::new(&env));
+        session_keys.remove((owner, session_pubkey));
+        env.storage().persistent().set(&symbol_short!("SessKeys"), &session_keys);
+    }
+
+    /// Enforce the configured per-country, per-asset transfer cooldown
+    /// (`TimeLimit`/`LastXfer`, see `set_transfer_interval`) and the
+    /// per-transaction/rolling-daily withdrawal caps (`WdLimit`/`DailyWd`,
+    /// see `set_withdrawal_limit`) for `signer` moving `amount`. Shared by
+    /// every custodial payment entry point so a payer can't bypass either
+    /// control by routing through a different one.
+    fn enforce_transfer_limits(env: &Env, signer: &Address, country_code: &String, asset: &Address, amount: i128) {
+        let interval = Self::get_transfer_interval(env.clone(), country_code.clone(), asset.clone());
+        if interval > 0 {
+            let mut last_transfer_times: Map<(Address, String, Address), u64> = env
+                .storage()
+                .persistent()
+                .get(&symbol_short!("LastXfer"))
+                .unwrap_or(Map::new(env));
+
+            let transfer_key = (signer.clone(), country_code.clone(), asset.clone());
+            let now = env.ledger().timestamp();
+            if let Some(last) = last_transfer_times.get(transfer_key.clone()) {
+                let elapsed = now.checked_sub(last).unwrap_or_else(|| panic!("Timestamp underflow"));
+                if elapsed < interval {
+                    panic!("Transfer interval has not elapsed");
+                }
+            }
+            last_transfer_times.set(transfer_key, now);
+            env.storage().persistent().set(&symbol_short!("LastXfer"), &last_transfer_times);
+        }
+
+        let withdrawal_limits: Map<(String, Address), WithdrawalLimit> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("WdLimit"))
+            .unwrap_or(Map::new(env));
+        if let Some(limit) = withdrawal_limits.get((country_code.clone(), asset.clone())) {
+            if amount > limit.max_per_tx {
+                panic!("Amount exceeds per-transaction withdrawal limit");
+            }
+
+            let day_index = env.ledger().timestamp() / 86400;
+            let mut daily_withdrawn: Map<(Address, String, Address, u64), i128> = env
+                .storage()
+                .persistent()
+                .get(&symbol_short!("DailyWd"))
+                .unwrap_or(Map::new(env));
+            let daily_key = (signer.clone(), country_code.clone(), asset.clone(), day_index);
+            let withdrawn_today = daily_withdrawn.get(daily_key.clone()).unwrap_or(0);
+            let new_withdrawn = withdrawn_today.checked_add(amount)
+                .unwrap_or_else(|| panic!("Daily withdrawal overflow"));
+            if new_withdrawn > limit.daily_max {
+                panic!("Amount exceeds daily withdrawal cap");
+            }
+            daily_withdrawn.set(daily_key, new_withdrawn);
+            env.storage().persistent().set(&symbol_short!("DailyWd"), &daily_withdrawn);
+        }
+    }
+
+    /// Execute a custodial payment authorized by a delegated session key
+    /// instead of a full account signature. Mirrors `execute_payment`'s
+    /// balance-check/transfer/decrement logic, including the shared
+    /// transfer-interval cooldown and withdrawal caps, so a session key
+    /// can't be used to bypass either control. The signed message includes
+    /// the session key's current `nonce`, which is incremented on every
+    /// successful call, so a captured `session_signature` can't be
+    /// resubmitted to repeat the same payment.
+    pub fn execute_payment_with_session(
+        env: Env,
+        owner: Address,
+        session_pubkey: BytesN<32>,
+        session_signature: BytesN<64>,
+        country_code: String,
+        destination: Address,
+        amount: i128,
+        asset: Address,
+    ) -> bool {
+        if !Self::validate_country_code(&env, &country_code) {
+            panic!("Invalid or disabled country code");
+        }
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let mut session_keys: Map<(Address, BytesN<32>), SessionKey> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SessKeys"))
+            .unwrap_or(Map::new(&env));
+        let session_map_key = (owner.clone(), session_pubkey.clone());
+        let mut session_key = session_keys.get(session_map_key.clone())
+            .unwrap_or_else(|| panic!("Unknown session key"));
+
+        if env.ledger().timestamp() >= session_key.expires_at {
+            panic!("Session key expired");
+        }
+
+        let new_spent = session_key.spent.checked_add(amount)
+            .unwrap_or_else(|| panic!("Spend overflow"));
+        if new_spent > session_key.spend_cap {
+            panic!("Session key spend cap exceeded");
+        }
+
+        let message = (owner.clone(), country_code.clone(), destination.clone(), amount, asset.clone(), session_key.nonce).to_xdr(&env);
+        env.crypto().ed25519_verify(&session_pubkey, &message, &session_signature);
+
+        let user_balance = Self::get_balance(env.clone(), owner.clone(), country_code.clone(), asset.clone());
+        if user_balance < amount {
+            panic!("Insufficient balance");
+        }
+
+        // Enforce the same transfer-interval cooldown and withdrawal caps
+        // `execute_payment` enforces, so a session key can't bypass either
+        Self::enforce_transfer_limits(&env, &owner, &country_code, &asset, amount);
+
+        let token_client = token::Client::new(&env, &asset);
+        let contract_address = env.current_contract_address();
+        token_client.transfer(&contract_address, &destination, &amount);
+
+        let mut balances_map: Map<Address, Map<String, Map<Address, i128>>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("Balances"))
+            .unwrap_or(Map::new(&env));
+
+        let mut user_balances: Map<String, Map<Address, i128>> = balances_map
+            .get(owner.clone())
+            .unwrap_or(Map::new(&env));
+
+        let mut country_balances: Map<Address, i128> = user_balances
+            .get(country_code.clone())
+            .unwrap_or(Map::new(&env));
+
+        let current_balance = country_balances.get(asset.clone()).unwrap_or(0);
+        let new_balance = current_balance.checked_sub(amount)
+            .unwrap_or_else(|| panic!("Balance underflow"));
+        country_balances.set(asset.clone(), new_balance);
+        user_balances.set(country_code.clone(), country_balances);
+        balances_map.set(owner.clone(), user_balances);
+        env.storage().persistent().set(&symbol_short!("Balances"), &balances_map);
+        Self::adjust_country_vault_total(&env, &country_code, &asset, -amount);
+
+        session_key.spent = new_spent;
+        session_key.nonce = session_key.nonce.checked_add(1).unwrap_or_else(|| panic!("Nonce overflow"));
+        session_keys.set(session_map_key, session_key);
+        env.storage().persistent().set(&symbol_short!("SessKeys"), &session_keys);
 
         true
     }
@@ -724,10 +1828,10 @@ impl GeoTrustMatch {
         destination: Address,
         amount: i128,
         asset: Address,
-        _signature_payload: Bytes, // WebAuthn signature payload (can be verified externally)
-        _webauthn_signature: Bytes, // WebAuthn signature (for future verification)
-        _webauthn_authenticator_data: Bytes, // WebAuthn authenticator data
-        _webauthn_client_data: Bytes, // WebAuthn client data JSON
+        signature_payload: Bytes, // WebAuthn signature payload (the challenge)
+        webauthn_signature: BytesN<64>, // raw (r || s) secp256r1 signature
+        webauthn_authenticator_data: Bytes,
+        webauthn_client_data: Bytes, // WebAuthn client_data_json
     ) -> bool {
         // Validate country code first
         if !Self::validate_country_code(&env, &country_code) {
@@ -738,6 +1842,15 @@ impl GeoTrustMatch {
             panic!("Amount must be positive");
         }
 
+        Self::verify_webauthn(
+            &env,
+            &signer_address,
+            &signature_payload,
+            &webauthn_signature,
+            &webauthn_authenticator_data,
+            &webauthn_client_data,
+        );
+
         // Require authorization from the user BEFORE token operations
         // Soroban's authorization framework handles signature verification and replay prevention
         signer_address.require_auth();
@@ -749,6 +1862,10 @@ impl GeoTrustMatch {
             panic!("Insufficient balance");
         }
 
+        // Enforce the configured transfer-interval cooldown and withdrawal
+        // caps, if any are set for this country/asset
+        Self::enforce_transfer_limits(&env, &signer_address, &country_code, &asset, amount);
+
         // Create token client for the asset
         let token_client = token::Client::new(&env, &asset);
         let contract_address = env.current_contract_address();
@@ -779,6 +1896,7 @@ impl GeoTrustMatch {
         user_balances.set(country_code.clone(), country_balances);
         balances_map.set(signer_address.clone(), user_balances);
         env.storage().persistent().set(&symbol_short!("Balances"), &balances_map);
+        Self::adjust_country_vault_total(&env, &country_code, &asset, -amount);
 
         true
     }
@@ -846,6 +1964,103 @@ impl GeoTrustMatch {
         result
     }
 
+    /// Set the minimum interval, in seconds, between `execute_payment` calls
+    /// for a given country/asset pair (admin-only). A value of 0 (the
+    /// default) means no rate limit.
+    pub fn set_transfer_interval(env: Env, country_code: String, asset: Address, seconds: u64) {
+        Self::require_admin_auth(&env, None);
+
+        let mut intervals: Map<(String, Address), u64> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("TimeLimit"))
+            .unwrap_or(Map::new(&env));
+
+        intervals.set((country_code, asset), seconds);
+        env.storage().persistent().set(&symbol_short!("TimeLimit"), &intervals);
+    }
+
+    /// Current transfer rate-limit interval, in seconds, for a country/asset
+    /// pair. 0 means unrestricted.
+    pub fn get_transfer_interval(env: Env, country_code: String, asset: Address) -> u64 {
+        let intervals: Map<(String, Address), u64> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("TimeLimit"))
+            .unwrap_or(Map::new(&env));
+
+        intervals.get((country_code, asset)).unwrap_or(0)
+    }
+
+    /// Set the per-transaction and rolling-daily withdrawal caps for a
+    /// country/asset pair (admin-only). `max_per_tx`/`daily_max` are given in
+    /// whole-token units (e.g. `1000` for 1000 USDC) - they are scaled by the
+    /// asset's `decimals()` and stored in the asset's smallest unit so
+    /// amounts compare directly against `execute_payment`'s `amount`.
+    pub fn set_withdrawal_limit(env: Env, country_code: String, asset: Address, max_per_tx: i128, daily_max: i128) {
+        Self::require_admin_auth(&env, None);
+
+        let token_client = token::Client::new(&env, &asset);
+        let decimals = token_client.decimals();
+        let scale = 10i128.checked_pow(decimals).unwrap_or_else(|| panic!("Decimals overflow"));
+
+        let limit = WithdrawalLimit {
+            max_per_tx: max_per_tx.checked_mul(scale).unwrap_or_else(|| panic!("Withdrawal limit overflow")),
+            daily_max: daily_max.checked_mul(scale).unwrap_or_else(|| panic!("Withdrawal limit overflow")),
+            decimals,
+        };
+
+        let mut limits: Map<(String, Address), WithdrawalLimit> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("WdLimit"))
+            .unwrap_or(Map::new(&env));
+        limits.set((country_code, asset), limit);
+        env.storage().persistent().set(&symbol_short!("WdLimit"), &limits);
+    }
+
+    /// Remaining daily withdrawal allowance (in the asset's smallest unit)
+    /// for `user` in a country/asset pair, for the current UTC day. Returns
+    /// `i128::MAX` when no limit is configured.
+    pub fn get_remaining_daily_allowance(env: Env, user: Address, country_code: String, asset: Address) -> i128 {
+        let limits: Map<(String, Address), WithdrawalLimit> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("WdLimit"))
+            .unwrap_or(Map::new(&env));
+
+        let limit = match limits.get((country_code.clone(), asset.clone())) {
+            Some(limit) => limit,
+            None => return i128::MAX,
+        };
+
+        let day_index = env.ledger().timestamp() / 86400;
+        let daily_withdrawn: Map<(Address, String, Address, u64), i128> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("DailyWd"))
+            .unwrap_or(Map::new(&env));
+        let withdrawn_today = daily_withdrawn.get((user, country_code, asset, day_index)).unwrap_or(0);
+
+        limit.daily_max.checked_sub(withdrawn_today).unwrap_or(0)
+    }
+
+    /// Get the aggregate vault total held across all users for a country
+    /// and asset - the sum of every depositor's balance there
+    pub fn get_country_vault_total(env: Env, country_code: String, asset: Address) -> i128 {
+        let vault_totals: Map<String, Map<Address, i128>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("CntVault"))
+            .unwrap_or(Map::new(&env));
+
+        vault_totals
+            .get(country_code)
+            .unwrap_or(Map::new(&env))
+            .get(asset)
+            .unwrap_or(0)
+    }
+
     /// Get total balance across all countries for a specific asset
     pub fn get_total_balance(
         env: Env,
@@ -872,6 +2087,282 @@ impl GeoTrustMatch {
 
         total
     }
+
+    /// Debit (negative `delta`) or credit (positive `delta`) a user's
+    /// country/asset balance in a single persistent write
+    fn adjust_user_balance(env: &Env, user: &Address, country_code: &String, asset: &Address, delta: i128) {
+        let mut balances_map: Map<Address, Map<String, Map<Address, i128>>> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("Balances"))
+            .unwrap_or(Map::new(env));
+
+        let mut user_balances: Map<String, Map<Address, i128>> = balances_map
+            .get(user.clone())
+            .unwrap_or(Map::new(env));
+
+        let mut country_balances: Map<Address, i128> = user_balances
+            .get(country_code.clone())
+            .unwrap_or(Map::new(env));
+
+        let current = country_balances.get(asset.clone()).unwrap_or(0);
+        let updated = current.checked_add(delta)
+            .unwrap_or_else(|| panic!("Balance over/underflow"));
+        if updated < 0 {
+            panic!("Insufficient balance");
+        }
+        country_balances.set(asset.clone(), updated);
+        user_balances.set(country_code.clone(), country_balances);
+        balances_map.set(user.clone(), user_balances);
+        env.storage().persistent().set(&symbol_short!("Balances"), &balances_map);
+    }
+
+    /// Set the treasury address that per-hop settlement fees are paid to
+    /// (admin-only)
+    pub fn set_settlement_treasury(env: Env, treasury: Address) {
+        Self::require_admin_auth(&env, None);
+        env.storage().instance().set(&symbol_short!("Treasury"), &treasury);
+        Self::extend_instance_ttl(&env);
+    }
+
+    /// Current settlement treasury address, if configured
+    pub fn get_settlement_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("Treasury"))
+    }
+
+    /// Move a user's balance from one country vault to another within this
+    /// contract's custody, optionally routing through intermediate country
+    /// vaults (e.g. `US -> EU -> KE`). Each hop debits and credits its two
+    /// country balances in one persistent write each, so there is never an
+    /// intermediate state where funds have left one vault but not yet
+    /// reached the next. `fee_bps` basis points are deducted from each hop
+    /// and forwarded to the configured settlement treasury. Returns the
+    /// amount that reached the final country after all hop fees.
+    pub fn transfer_between_countries(
+        env: Env,
+        signer_address: Address,
+        path: Vec<String>,
+        asset: Address,
+        amount: i128,
+        fee_bps: u32,
+    ) -> i128 {
+        signer_address.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if path.len() < 2 {
+            panic!("Path must include at least a source and destination country");
+        }
+        if fee_bps > 10_000 {
+            panic!("Fee basis points must be at most 10000");
+        }
+
+        for country_code in path.iter() {
+            if !Self::validate_country_code(&env, &country_code) {
+                panic!("Invalid or disabled country code in path");
+            }
+        }
+
+        let source = path.get(0).unwrap_or_else(|| panic!("Missing source country"));
+        let source_balance = Self::get_balance(env.clone(), signer_address.clone(), source.clone(), asset.clone());
+        if source_balance < amount {
+            panic!("Insufficient balance");
+        }
+
+        let token_client = token::Client::new(&env, &asset);
+        let contract_address = env.current_contract_address();
+
+        let mut running_amount = amount;
+        let mut i = 0u32;
+        while i + 1 < path.len() {
+            let from_country = path.get(i).unwrap_or_else(|| panic!("Missing path entry"));
+            let to_country = path.get(i + 1).unwrap_or_else(|| panic!("Missing path entry"));
+
+            let fee = running_amount.checked_mul(fee_bps as i128)
+                .unwrap_or_else(|| panic!("Fee overflow")) / 10_000;
+            let net_amount = running_amount.checked_sub(fee)
+                .unwrap_or_else(|| panic!("Fee underflow"));
+
+            Self::adjust_user_balance(&env, &signer_address, &from_country, &asset, -running_amount);
+            Self::adjust_user_balance(&env, &signer_address, &to_country, &asset, net_amount);
+            Self::adjust_country_vault_total(&env, &from_country, &asset, -running_amount);
+            Self::adjust_country_vault_total(&env, &to_country, &asset, net_amount);
+
+            if fee > 0 {
+                if let Some(treasury) = Self::get_settlement_treasury(env.clone()) {
+                    token_client.transfer(&contract_address, &treasury, &fee);
+                }
+            }
+
+            env.events().publish(
+                (symbol_short!("XferHop"), from_country.clone(), to_country.clone()),
+                (signer_address.clone(), net_amount, fee),
+            );
+
+            running_amount = net_amount;
+            i = i.checked_add(1).unwrap_or_else(|| panic!("Hop count overflow"));
+        }
+
+        running_amount
+    }
+
+    // ========== Threshold Document Key Reveal ==========
+
+    /// Register the M-of-N key-server committee that can jointly reconstruct
+    /// a session's document key (main admin-only)
+    pub fn set_key_servers(env: Env, servers: Vec<Address>, threshold: u32) {
+        Self::require_admin_auth(&env, None);
+
+        if servers.is_empty() || threshold == 0 || threshold > servers.len() {
+            panic!("Threshold must be between 1 and the key server set size");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("KeySrvs"), &servers);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("KeySrvThr"), &threshold);
+    }
+
+    /// Get the registered key-server committee and required threshold
+    pub fn get_key_servers(env: Env) -> (Vec<Address>, u32) {
+        let servers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("KeySrvs"))
+            .unwrap_or(vec![&env]);
+        let threshold: u32 = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("KeySrvThr"))
+            .unwrap_or(0);
+        (servers, threshold)
+    }
+
+    /// Open a reveal request for a session's encrypted coordinates. Only
+    /// callable once the session has ended with a confirmed match - losers
+    /// and observers of a non-matched or still-active session never get a
+    /// decryption key.
+    pub fn request_reveal(env: Env, session_id: u32, requester: Address) {
+        requester.require_auth();
+
+        let session_key = (symbol_short!("Session"), session_id);
+        let session: Session = env
+            .storage()
+            .temporary()
+            .get(&session_key)
+            .unwrap_or_else(|| panic!("Session not found"));
+
+        if session.state != SessionState::Ended {
+            panic!("Session not ended");
+        }
+        if session.pending_matched != Some(true) {
+            panic!("Session did not resolve to a match");
+        }
+
+        let request_key = (symbol_short!("RevealReq"), session_id);
+        let request = RevealRequest {
+            requester,
+            shares: Map::new(&env),
+            revealed: false,
+        };
+        env.storage().temporary().set(&request_key, &request);
+        env.storage().temporary().extend_ttl(&request_key, 100000, 100001);
+    }
+
+    /// Submit this key server's share toward reconstructing a session's
+    /// document key. Once `threshold` distinct shares are in, the key is
+    /// reconstructed and the coordinates become readable via
+    /// `get_revealed_coordinates`.
+    pub fn submit_share(env: Env, session_id: u32, server_index: u32, share: Bytes) {
+        let (servers, threshold) = Self::get_key_servers(env.clone());
+        let server_addr = servers.get(server_index)
+            .unwrap_or_else(|| panic!("Unknown key server index"));
+        server_addr.require_auth();
+
+        let request_key = (symbol_short!("RevealReq"), session_id);
+        let mut request: RevealRequest = env
+            .storage()
+            .temporary()
+            .get(&request_key)
+            .unwrap_or_else(|| panic!("No reveal request pending for session"));
+
+        if request.revealed {
+            panic!("Session coordinates already revealed");
+        }
+        if request.shares.contains_key(server_index) {
+            panic!("Key server already submitted a share");
+        }
+
+        request.shares.set(server_index, share);
+
+        if request.shares.len() >= threshold {
+            let session_key = (symbol_short!("Session"), session_id);
+            let session: Session = env
+                .storage()
+                .temporary()
+                .get(&session_key)
+                .unwrap_or_else(|| panic!("Session not found"));
+
+            let revealed = Self::reconstruct_coordinates(&env, &session, &request.shares);
+            request.revealed = true;
+
+            let coords_key = (symbol_short!("RevldCrd"), session_id);
+            env.storage().temporary().set(&coords_key, &revealed);
+            env.storage().temporary().extend_ttl(&coords_key, 100000, 100001);
+
+            env.events()
+                .publish((symbol_short!("Revealed"), session_id), ());
+        }
+
+        env.storage().temporary().set(&request_key, &request);
+        env.storage().temporary().extend_ttl(&request_key, 100000, 100001);
+    }
+
+    /// Combine key-server shares into the session's document key and use it
+    /// to decrypt the matched players' encrypted coordinates. The shares are
+    /// XORed together into the key - a simplified stand-in for a real
+    /// threshold scheme (e.g. Shamir), mirroring the simplified proof
+    /// encoding elsewhere in this codebase.
+    fn reconstruct_coordinates(env: &Env, session: &Session, shares: &Map<u32, Bytes>) -> Bytes {
+        let mut document_key = Bytes::new(env);
+        for (_, share) in shares.iter() {
+            document_key = Self::xor_bytes(env, &document_key, &share);
+        }
+
+        let p1 = session.p1_encrypted_coords.clone().unwrap_or(Bytes::new(env));
+        let p2 = session.p2_encrypted_coords.clone().unwrap_or(Bytes::new(env));
+        let p1_plain = Self::xor_bytes(env, &p1, &document_key);
+        let p2_plain = Self::xor_bytes(env, &p2, &document_key);
+
+        let mut out = p1_plain;
+        out.append(&p2_plain);
+        out
+    }
+
+    /// XOR two `Bytes` up to the length of the longer one, treating any
+    /// missing bytes in the shorter operand as zero
+    fn xor_bytes(env: &Env, a: &Bytes, b: &Bytes) -> Bytes {
+        let len = a.len().max(b.len());
+        let mut out = Bytes::new(env);
+        let mut i = 0u32;
+        while i < len {
+            let a_byte = a.get(i).unwrap_or(0);
+            let b_byte = b.get(i).unwrap_or(0);
+            out.push_back(a_byte ^ b_byte);
+            i += 1;
+        }
+        out
+    }
+
+    /// Read the revealed coordinates for a session, once enough key-server
+    /// shares have been submitted
+    pub fn get_revealed_coordinates(env: Env, session_id: u32) -> Option<Bytes> {
+        let coords_key = (symbol_short!("RevldCrd"), session_id);
+        env.storage().temporary().get(&coords_key)
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{symbol_short, testutils::{Address as _, Ledger as _}, Address, BytesN, Env, String};
 
 #[test]
 fn test_init() {
@@ -25,9 +25,9 @@ fn test_country_policy() {
     });
 
     env.as_contract(&contract_id, || {
-        GeoTrustMatch::set_country_allowed(env.clone(), 840u16, true);
-        assert_eq!(GeoTrustMatch::get_country_allowed(env.clone(), 840u16), Some(true));
-        assert_eq!(GeoTrustMatch::get_country_allowed(env.clone(), 826u16), None);
+        GeoTrustMatch::set_country_allowed(env.clone(), 840u32, true);
+        assert_eq!(GeoTrustMatch::get_country_allowed(env.clone(), 840u32), Some(true));
+        assert_eq!(GeoTrustMatch::get_country_allowed(env.clone(), 826u32), None);
     });
 }
 
@@ -44,18 +44,40 @@ fn test_session_flow() {
     });
 
     let session_id = env.as_contract(&contract_id, || {
-        GeoTrustMatch::create_session(env.clone())
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
     });
     assert_eq!(session_id, 1u32);
 
     let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
-    
+    let coords1 = Bytes::from_array(&env, &[1u8; 8]);
+    let coords2 = Bytes::from_array(&env, &[2u8; 8]);
+
     env.as_contract(&contract_id, || {
-        GeoTrustMatch::join_session(env.clone(), session_id, 1u32, asset_tag.clone(), 840u16);
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player1.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            coords1.clone(),
+        );
     });
 
     env.as_contract(&contract_id, || {
-        GeoTrustMatch::join_session(env.clone(), session_id, 1u32, asset_tag.clone(), 840u16);
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player2.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            coords2.clone(),
+        );
     });
 
     let result = env.as_contract(&contract_id, || {
@@ -63,3 +85,672 @@ fn test_session_flow() {
     });
     assert!(result.matched);
 }
+
+#[test]
+fn test_policy_version_freezes_in_flight_sessions() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let version_at_creation = env.as_contract(&contract_id, || {
+        GeoTrustMatch::get_policy_version(env.clone())
+    });
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::set_country_allowed(env.clone(), 840u32, true);
+    });
+
+    let version_after_change = env.as_contract(&contract_id, || {
+        GeoTrustMatch::get_policy_version(env.clone())
+    });
+
+    assert!(version_after_change > version_at_creation);
+
+    let recorded_version = env.as_contract(&contract_id, || {
+        GeoTrustMatch::get_session(env.clone(), session_id)
+            .unwrap_or_else(|| panic!("Session not found"))
+            .policy_version
+    });
+    assert_eq!(recorded_version, version_at_creation);
+}
+
+#[test]
+fn test_register_webauthn_key() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let user = Address::generate(&env);
+    let public_key = BytesN::from_array(&env, &[7u8; 65]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::register_webauthn_key(env.clone(), user.clone(), public_key.clone());
+    });
+}
+
+#[test]
+fn test_set_challenge_window_updates_window() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_challenge_window(env.clone(), 10);
+    });
+
+    assert_eq!(
+        env.as_contract(&contract_id, || GeoTrustMatch::get_challenge_window(env.clone())),
+        10
+    );
+}
+
+#[test]
+fn test_effective_policy_region_fallback() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), false);
+    });
+
+    env.as_contract(&contract_id, || {
+        // Block all of region 150 (Europe) ...
+        GeoTrustMatch::set_region_allowed(env.clone(), 150u32, false);
+        GeoTrustMatch::set_country_region(env.clone(), 276u32, 150u32); // Germany
+        GeoTrustMatch::set_country_region(env.clone(), 250u32, 150u32); // France
+        // ... except France, via an explicit country override
+        GeoTrustMatch::set_country_allowed(env.clone(), 250u32, true);
+
+        assert_eq!(GeoTrustMatch::get_effective_policy(env.clone(), 276u32), false);
+        assert_eq!(GeoTrustMatch::get_effective_policy(env.clone(), 250u32), true);
+        // No region assigned and no override: falls back to the global default
+        assert_eq!(GeoTrustMatch::get_effective_policy(env.clone(), 840u32), false);
+    });
+}
+
+#[test]
+fn test_threshold_reveal_requires_quorum_shares() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let server1 = Address::generate(&env);
+    let server2 = Address::generate(&env);
+    let server3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_key_servers(
+            env.clone(),
+            soroban_sdk::vec![&env, server1.clone(), server2.clone(), server3.clone()],
+            2,
+        );
+        GeoTrustMatch::set_challenge_window(env.clone(), 0);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
+    let coords1 = Bytes::from_array(&env, &[1u8; 8]);
+    let coords2 = Bytes::from_array(&env, &[2u8; 8]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player1.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            coords1.clone(),
+        );
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player2.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            coords2.clone(),
+        );
+        GeoTrustMatch::resolve_match(env.clone(), session_id);
+        GeoTrustMatch::finalize_match(env.clone(), session_id);
+    });
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::request_reveal(env.clone(), session_id, player1.clone());
+    });
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::submit_share(env.clone(), session_id, 0, Bytes::from_array(&env, &[9u8; 4]));
+        assert_eq!(GeoTrustMatch::get_revealed_coordinates(env.clone(), session_id), None);
+
+        GeoTrustMatch::submit_share(env.clone(), session_id, 1, Bytes::from_array(&env, &[8u8; 4]));
+        assert!(GeoTrustMatch::get_revealed_coordinates(env.clone(), session_id).is_some());
+    });
+}
+
+#[test]
+fn test_threshold_reveal_decrypts_to_the_original_coordinates() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let server1 = Address::generate(&env);
+    let server2 = Address::generate(&env);
+
+    // Shares XOR together into this document key
+    let key: [u8; 4] = [0x11, 0x22, 0x33, 0x44];
+    let share_a: [u8; 4] = [0xF0, 0xF0, 0xF0, 0xF0];
+    let share_b: [u8; 4] = [
+        key[0] ^ share_a[0],
+        key[1] ^ share_a[1],
+        key[2] ^ share_a[2],
+        key[3] ^ share_a[3],
+    ];
+
+    // Each player's plaintext coordinates, encrypted under the key above
+    let plaintext1: [u8; 4] = [0xAA, 0xBB, 0xCC, 0xDD];
+    let plaintext2: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    let ciphertext1: [u8; 4] = [
+        plaintext1[0] ^ key[0],
+        plaintext1[1] ^ key[1],
+        plaintext1[2] ^ key[2],
+        plaintext1[3] ^ key[3],
+    ];
+    let ciphertext2: [u8; 4] = [
+        plaintext2[0] ^ key[0],
+        plaintext2[1] ^ key[1],
+        plaintext2[2] ^ key[2],
+        plaintext2[3] ^ key[3],
+    ];
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_key_servers(
+            env.clone(),
+            soroban_sdk::vec![&env, server1.clone(), server2.clone()],
+            2,
+        );
+        GeoTrustMatch::set_challenge_window(env.clone(), 0);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player1.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            Bytes::from_array(&env, &ciphertext1),
+        );
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player2.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            Bytes::from_array(&env, &ciphertext2),
+        );
+        GeoTrustMatch::resolve_match(env.clone(), session_id);
+        GeoTrustMatch::finalize_match(env.clone(), session_id);
+
+        GeoTrustMatch::request_reveal(env.clone(), session_id, player1.clone());
+        GeoTrustMatch::submit_share(env.clone(), session_id, 0, Bytes::from_array(&env, &share_a));
+        GeoTrustMatch::submit_share(env.clone(), session_id, 1, Bytes::from_array(&env, &share_b));
+
+        let mut expected = Bytes::from_array(&env, &plaintext1);
+        expected.append(&Bytes::from_array(&env, &plaintext2));
+        assert_eq!(GeoTrustMatch::get_revealed_coordinates(env.clone(), session_id), Some(expected));
+    });
+}
+
+#[test]
+fn test_transfer_between_countries_requires_balance() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::register_country(
+            env.clone(),
+            String::from_str(&env, "US"),
+            String::from_str(&env, "United States"),
+            admin.clone(),
+        );
+        GeoTrustMatch::register_country(
+            env.clone(),
+            String::from_str(&env, "KE"),
+            String::from_str(&env, "Kenya"),
+            admin.clone(),
+        );
+    });
+
+    let path = soroban_sdk::vec![&env, String::from_str(&env, "US"), String::from_str(&env, "KE")];
+
+    let result = env.as_contract(&contract_id, || {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            GeoTrustMatch::transfer_between_countries(env.clone(), user.clone(), path.clone(), asset.clone(), 100, 0)
+        }))
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_register_and_revoke_session_key() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let owner = Address::generate(&env);
+    let session_pubkey = BytesN::from_array(&env, &[4u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::register_session_key(env.clone(), owner.clone(), session_pubkey.clone(), 1_000_000, 500);
+        GeoTrustMatch::revoke_session_key(env.clone(), owner.clone(), session_pubkey.clone());
+    });
+}
+
+// `execute_payment_with_session`'s replay protection is cryptographic (the
+// session key's `nonce` is folded into the ed25519-signed message, see
+// chunk2-4), not a distinct panic branch, and minting a real ed25519
+// signature isn't possible with the dependencies available in this sandbox
+// (same constraint noted below for `execute_payment`). This instead checks
+// the stored `SessionKey` state directly: a freshly registered key starts
+// at `nonce: 0`, and bumping it persists the way `execute_payment_with_session`
+// relies on to make a captured signature unusable a second time.
+#[test]
+fn test_session_key_nonce_starts_at_zero_and_persists_bump() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let owner = Address::generate(&env);
+    let session_pubkey = BytesN::from_array(&env, &[7u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::register_session_key(env.clone(), owner.clone(), session_pubkey.clone(), 1_000_000, 500);
+
+        let session_keys: Map<(Address, BytesN<32>), SessionKey> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SessKeys"))
+            .unwrap();
+        let key = (owner.clone(), session_pubkey.clone());
+        let session_key = session_keys.get(key.clone()).unwrap();
+        assert_eq!(session_key.nonce, 0);
+
+        let mut session_keys = session_keys;
+        let mut bumped = session_key;
+        bumped.nonce += 1;
+        session_keys.set(key.clone(), bumped);
+        env.storage().persistent().set(&symbol_short!("SessKeys"), &session_keys);
+
+        let session_keys: Map<(Address, BytesN<32>), SessionKey> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SessKeys"))
+            .unwrap();
+        assert_eq!(session_keys.get(key).unwrap().nonce, 1);
+    });
+}
+
+#[test]
+fn test_transfer_interval_defaults_to_unrestricted() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+
+        assert_eq!(
+            GeoTrustMatch::get_transfer_interval(env.clone(), country_code.clone(), asset.clone()),
+            0
+        );
+
+        GeoTrustMatch::set_transfer_interval(env.clone(), country_code.clone(), asset.clone(), 3600);
+        assert_eq!(
+            GeoTrustMatch::get_transfer_interval(env.clone(), country_code.clone(), asset.clone()),
+            3600
+        );
+    });
+}
+
+// `execute_payment`/`execute_payment_with_session` gate every transfer on a
+// real secp256r1 WebAuthn assertion (or, for the session path, an ed25519
+// signature), which this crate has no dependency available to mint in a
+// test. These exercise `enforce_transfer_limits` directly instead -- the
+// shared helper both entry points route through (see chunk2-4) -- to cover
+// the cooldown and withdrawal-cap enforcement itself.
+
+#[test]
+#[should_panic(expected = "Transfer interval has not elapsed")]
+fn test_transfer_interval_cooldown_blocks_early_retry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_transfer_interval(env.clone(), country_code.clone(), asset.clone(), 3600);
+
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 10);
+        // Cooldown hasn't elapsed yet -- must panic
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 10);
+    });
+}
+
+#[test]
+fn test_transfer_interval_cooldown_allows_after_elapsed() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_transfer_interval(env.clone(), country_code.clone(), asset.clone(), 3600);
+
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 10);
+    });
+
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+
+    env.as_contract(&contract_id, || {
+        // Cooldown has now elapsed -- should not panic
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds per-transaction withdrawal limit")]
+fn test_withdrawal_limit_blocks_amount_over_per_tx_cap() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let signer = Address::generate(&env);
+    // Limits are configured in whole tokens and scaled by the token's decimals()
+    let asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let scale = 10i128.pow(token::Client::new(&env, &asset).decimals());
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_withdrawal_limit(env.clone(), country_code.clone(), asset.clone(), 100, 1000);
+
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 101 * scale);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds daily withdrawal cap")]
+fn test_withdrawal_limit_blocks_cumulative_amount_over_daily_cap() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let scale = 10i128.pow(token::Client::new(&env, &asset).decimals());
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_withdrawal_limit(env.clone(), country_code.clone(), asset.clone(), 100, 150);
+
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 100 * scale);
+        // 100 + 60 > 150 daily cap -- must panic even though 60 is under max_per_tx
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 60 * scale);
+    });
+}
+
+#[test]
+fn test_withdrawal_limit_allows_amounts_within_caps() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let scale = 10i128.pow(token::Client::new(&env, &asset).decimals());
+    let country_code = String::from_str(&env, "US");
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_withdrawal_limit(env.clone(), country_code.clone(), asset.clone(), 100, 150);
+
+        GeoTrustMatch::enforce_transfer_limits(&env, &signer, &country_code, &asset, 100 * scale);
+        assert_eq!(
+            GeoTrustMatch::get_remaining_daily_allowance(env.clone(), signer.clone(), country_code.clone(), asset.clone()),
+            50 * scale
+        );
+    });
+}
+
+#[test]
+fn test_cancel_session_by_admin_before_timeout() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    env.as_contract(&contract_id, || {
+        // Admin can reclaim immediately, without waiting for the timeout
+        GeoTrustMatch::cancel_session(env.clone(), session_id, admin.clone());
+        assert_eq!(GeoTrustMatch::get_session(env.clone(), session_id), None);
+    });
+}
+
+#[test]
+fn test_cancel_session_by_player1_requires_timeout() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_session_timeout(env.clone(), 10);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::join_session(
+            env.clone(),
+            player1.clone(),
+            session_id,
+            1u32,
+            asset_tag.clone(),
+            840u32,
+            None,
+            soroban_sdk::vec![&env],
+            Bytes::new(&env),
+        );
+    });
+
+    // Timeout has not elapsed yet, so player1 cancelling should panic.
+    let result = env.as_contract(&contract_id, || {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            GeoTrustMatch::cancel_session(env.clone(), session_id, player1.clone())
+        }))
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cancel_session_rejects_active_session() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_session_timeout(env.clone(), 10);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::join_session(
+            env.clone(), player1.clone(), session_id, 1u32, asset_tag.clone(),
+            840u32, None, soroban_sdk::vec![&env], Bytes::new(&env),
+        );
+        GeoTrustMatch::join_session(
+            env.clone(), player2.clone(), session_id, 1u32, asset_tag.clone(),
+            840u32, None, soroban_sdk::vec![&env], Bytes::new(&env),
+        );
+        assert_eq!(GeoTrustMatch::get_session(env.clone(), session_id).unwrap().state, SessionState::Active);
+    });
+
+    env.ledger().with_mut(|l| l.sequence_number += 100);
+
+    // Session is Active (both players joined, no outcome yet) -- player1
+    // cancelling now would erase the match instead of letting it resolve.
+    let result = env.as_contract(&contract_id, || {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            GeoTrustMatch::cancel_session(env.clone(), session_id, player1.clone())
+        }))
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cancel_session_rejects_pending_session() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_session_timeout(env.clone(), 10);
+    });
+
+    let session_id = env.as_contract(&contract_id, || {
+        GeoTrustMatch::create_session(env.clone(), player1.clone())
+    });
+
+    let asset_tag = BytesN::from_array(&env, &[0u8; 32]);
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::join_session(
+            env.clone(), player1.clone(), session_id, 1u32, asset_tag.clone(),
+            840u32, None, soroban_sdk::vec![&env], Bytes::new(&env),
+        );
+        GeoTrustMatch::join_session(
+            env.clone(), player2.clone(), session_id, 1u32, asset_tag.clone(),
+            840u32, None, soroban_sdk::vec![&env], Bytes::new(&env),
+        );
+        GeoTrustMatch::resolve_match(env.clone(), session_id);
+        assert_eq!(GeoTrustMatch::get_session(env.clone(), session_id).unwrap().state, SessionState::Pending);
+    });
+
+    env.ledger().with_mut(|l| l.sequence_number += 100);
+
+    // Session is Pending (resolved, awaiting the challenge window) --
+    // cancelling now would erase the recorded outcome with no settlement.
+    let result = env.as_contract(&contract_id, || {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            GeoTrustMatch::cancel_session(env.clone(), session_id, player1.clone())
+        }))
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_verifier_quorum_registration() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+    let verifier1 = Address::generate(&env);
+    let verifier2 = Address::generate(&env);
+    let verifier3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+        GeoTrustMatch::set_verifiers(
+            env.clone(),
+            soroban_sdk::vec![&env, verifier1.clone(), verifier2.clone(), verifier3.clone()],
+            2,
+        );
+        let (verifiers, threshold) = GeoTrustMatch::get_verifiers(env.clone());
+        assert_eq!(verifiers.len(), 3);
+        assert_eq!(threshold, 2);
+    });
+}
+
+#[test]
+fn test_oracle_set() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, GeoTrustMatch);
+    let admin = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::init(env.clone(), admin.clone(), true);
+    });
+
+    let oracle1 = BytesN::from_array(&env, &[1u8; 32]);
+    let oracle2 = BytesN::from_array(&env, &[2u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        GeoTrustMatch::set_oracle_set(
+            env.clone(),
+            soroban_sdk::vec![&env, oracle1.clone(), oracle2.clone()],
+            2,
+        );
+        let (oracles, threshold) = GeoTrustMatch::get_oracle_set(env.clone());
+        assert_eq!(oracles.len(), 2);
+        assert_eq!(threshold, 2);
+    });
+}
@@ -1,18 +1,65 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec, Map,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Vec, Map,
 };
 use soroban_sdk::crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr};
 
+/// Read a fixed-size, big-endian field out of a calldata `Bytes` blob at
+/// `start`. Panics with a clear error on truncated input rather than
+/// silently zero-padding.
+fn read_fixed_bytes<const N: usize>(bytes: &Bytes, start: u32) -> [u8; N] {
+    let mut out = [0u8; N];
+    for i in 0..N {
+        out[i] = bytes.get(start + i as u32)
+            .unwrap_or_else(|| panic!("Calldata truncated"));
+    }
+    out
+}
+
+/// Read a big-endian `u32` length/count prefix out of a calldata blob
+fn read_u32_prefix(bytes: &Bytes, start: u32) -> u32 {
+    u32::from_be_bytes(read_fixed_bytes::<4>(bytes, start))
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct LocationProof {
-    pub proof: BytesN<64>, // ZK proof bytes (Groth16 format: A (64 bytes), B (128 bytes), C (64 bytes) = 256 bytes total, but we use 64 for simplified)
-    pub public_inputs: Vec<u32>, // [cell_id, grid_size_scaled]
+    pub proof: BytesN<256>, // Groth16 proof: A (G1, 64 bytes) || B (G2, 128 bytes) || C (G1, 64 bytes)
+    pub public_inputs: Vec<BytesN<32>>, // big-endian Fr field elements: [cell_id, grid_size_scaled, ...]
+}
+
+impl LocationProof {
+    /// Encode as one flat calldata blob: `proof` (256 bytes) || input count
+    /// (u32 big-endian) || each public input (32 bytes), mirroring the
+    /// `encode_calldata` layout on-chain verifier generators emit.
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut out = Bytes::from_array(env, &self.proof.to_array());
+        out.append(&Bytes::from_array(env, &(self.public_inputs.len()).to_be_bytes()));
+        for input in self.public_inputs.iter() {
+            out.append(&Bytes::from_array(env, &input.to_array()));
+        }
+        out
+    }
+
+    /// Decode a blob produced by `to_bytes`. Panics on truncated input.
+    pub fn from_bytes(env: &Env, bytes: &Bytes) -> Self {
+        let proof = BytesN::from_array(env, &read_fixed_bytes::<256>(bytes, 0));
+        let input_count = read_u32_prefix(bytes, 256);
+
+        let mut public_inputs = Vec::new(env);
+        let mut offset = 260u32;
+        for _ in 0..input_count {
+            let field_element = BytesN::from_array(env, &read_fixed_bytes::<32>(bytes, offset));
+            public_inputs.push_back(field_element);
+            offset += 32;
+        }
+
+        LocationProof { proof, public_inputs }
+    }
 }
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct VerificationKey {
     pub alpha_g1: BytesN<64>,   // alpha * G1 generator
     pub beta_g2: BytesN<128>,   // beta * G2 generator
@@ -21,6 +68,66 @@ pub struct VerificationKey {
     pub ic: Vec<BytesN<64>>,    // gamma_abc (public input commitments)
 }
 
+impl VerificationKey {
+    /// Encode as one flat calldata blob: `alpha_g1` (64) || `beta_g2` (128)
+    /// || `gamma_g2` (128) || `delta_g2` (128) || IC count (u32 big-endian)
+    /// || each IC point (64 bytes)
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut out = Bytes::from_array(env, &self.alpha_g1.to_array());
+        out.append(&Bytes::from_array(env, &self.beta_g2.to_array()));
+        out.append(&Bytes::from_array(env, &self.gamma_g2.to_array()));
+        out.append(&Bytes::from_array(env, &self.delta_g2.to_array()));
+        out.append(&Bytes::from_array(env, &(self.ic.len()).to_be_bytes()));
+        for ic_point in self.ic.iter() {
+            out.append(&Bytes::from_array(env, &ic_point.to_array()));
+        }
+        out
+    }
+
+    /// Decode a blob produced by `to_bytes`. Panics on truncated input.
+    pub fn from_bytes(env: &Env, bytes: &Bytes) -> Self {
+        let alpha_g1 = BytesN::from_array(env, &read_fixed_bytes::<64>(bytes, 0));
+        let beta_g2 = BytesN::from_array(env, &read_fixed_bytes::<128>(bytes, 64));
+        let gamma_g2 = BytesN::from_array(env, &read_fixed_bytes::<128>(bytes, 192));
+        let delta_g2 = BytesN::from_array(env, &read_fixed_bytes::<128>(bytes, 320));
+        let ic_count = read_u32_prefix(bytes, 448);
+
+        let mut ic = Vec::new(env);
+        let mut offset = 452u32;
+        for _ in 0..ic_count {
+            let ic_point = BytesN::from_array(env, &read_fixed_bytes::<64>(bytes, offset));
+            ic.push_back(ic_point);
+            offset += 64;
+        }
+
+        VerificationKey { alpha_g1, beta_g2, gamma_g2, delta_g2, ic }
+    }
+}
+
+/// Typed failure modes for `verify_checked`, replacing the ambiguous `false`
+/// result that `verify` used to return for every rejection reason.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerifyError {
+    PublicInputMismatch = 1,
+    MalformedProof = 2,
+    VerificationKeyNotSet = 3,
+    PairingFailed = 4,
+    GridSizeOutOfRange = 5,
+    CellIdOutOfRange = 6,
+    ReplayDetected = 7,
+}
+
+/// Per-proof outcome for `verify_batch_checked`: `error` is `None` on success,
+/// otherwise the specific reason that proof was rejected.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchVerifyResult {
+    pub ok: bool,
+    pub error: Option<VerifyError>,
+}
+
 #[contract]
 pub struct ZkVerifier;
 
@@ -35,240 +142,338 @@ impl ZkVerifier {
         env.storage().instance().set(&symbol_short!("Nonces"), &nonce_map);
     }
 
-    /// Verify a location proof using Protocol 25 BN254 pairing and Poseidon hashing
-    /// Implements full cryptographic Groth16 proof verification
+    /// Round constant for Poseidon round `round`, state slot `index`,
+    /// state width `t = 3`. Derived deterministically via a domain-separated
+    /// SHA256 expansion rather than the Grain LFSR generator the reference
+    /// implementation uses, since this crate doesn't vendor one; the
+    /// algebraic property Poseidon needs from these - independent,
+    /// unpredictable, non-zero field elements - still holds.
+    fn poseidon_round_constant(env: &Env, round: u32, index: u32) -> Fr {
+        let mut preimage = Bytes::from_array(env, b"GeoTrust-Poseidon-RC-t3--------");
+        preimage.append(&Bytes::from_array(env, &round.to_be_bytes()));
+        preimage.append(&Bytes::from_array(env, &index.to_be_bytes()));
+        let digest = env.crypto().sha256(&preimage).to_array();
+        Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &digest)))
+    }
+
+    /// MDS matrix entry `(row, col)` for the `t = 3` Poseidon state, derived
+    /// the same way as `poseidon_round_constant`.
+    fn poseidon_mds_entry(env: &Env, row: u32, col: u32) -> Fr {
+        let mut preimage = Bytes::from_array(env, b"GeoTrust-Poseidon-MDS-t3-------");
+        preimage.append(&Bytes::from_array(env, &row.to_be_bytes()));
+        preimage.append(&Bytes::from_array(env, &col.to_be_bytes()));
+        let digest = env.crypto().sha256(&preimage).to_array();
+        Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &digest)))
+    }
+
+    /// Poseidon S-box: `x^5`.
+    fn poseidon_sbox(x: &Fr) -> Fr {
+        let x2 = x.clone() * x.clone();
+        let x4 = x2.clone() * x2;
+        x4 * x.clone()
+    }
+
+    /// Poseidon permutation over the BN254 scalar field with state width
+    /// `t = arity + 1 = 3` (2-to-1 compression), the only arity this
+    /// contract needs. State is initialized to `[0, in_0, in_1]`. Runs
+    /// `R_F = 8` full rounds (4 before / 4 after the partial rounds) and
+    /// `R_P = 57` partial rounds; each round adds round constants to the
+    /// whole state, applies the `x^5` S-box (every slot in full rounds,
+    /// only `state[0]` in partial rounds), then multiplies the state by the
+    /// fixed MDS matrix. Returns `state[0]` as the digest.
+    fn poseidon_hash(env: &Env, inputs: [Fr; 2]) -> Fr {
+        const T: usize = 3;
+        const R_F: u32 = 8;
+        const R_P: u32 = 57;
+
+        let zero = Fr::from_u256(soroban_sdk::U256::from_u32(env, 0));
+        let mut state: [Fr; T] = [zero, inputs[0].clone(), inputs[1].clone()];
+
+        let total_rounds = R_F + R_P;
+        for round in 0..total_rounds {
+            for i in 0..T {
+                state[i] = state[i].clone() + Self::poseidon_round_constant(env, round, i as u32);
+            }
+
+            let is_full_round = round < R_F / 2 || round >= R_F / 2 + R_P;
+            if is_full_round {
+                for i in 0..T {
+                    state[i] = Self::poseidon_sbox(&state[i]);
+                }
+            } else {
+                state[0] = Self::poseidon_sbox(&state[0]);
+            }
+
+            let prev_state = state.clone();
+            for row in 0..T {
+                let mut acc = Fr::from_u256(soroban_sdk::U256::from_u32(env, 0));
+                for col in 0..T {
+                    let entry = Self::poseidon_mds_entry(env, row as u32, col as u32);
+                    acc = acc + prev_state[col].clone() * entry;
+                }
+                state[row] = acc;
+            }
+        }
+
+        state[0].clone()
+    }
+
+    /// Reduce a BN254 Fr digest back down to 32 big-endian bytes.
+    fn fr_to_bytes(value: &Fr) -> [u8; 32] {
+        let digest_bytes = value.to_u256().to_be_bytes();
+        let mut out = [0u8; 32];
+        for i in 0..32 {
+            out[i] = digest_bytes.get(i as u32).unwrap_or_else(|| panic!("Fr digest truncated"));
+        }
+        out
+    }
+
+    /// Decode a big-endian Fr field element down to a u32, for the cell-id
+    /// and grid-size public inputs that still fit that range. Fails if any
+    /// of the high 28 bytes are non-zero.
+    fn fr_to_u32(field_element: &BytesN<32>) -> Option<u32> {
+        let bytes = field_element.to_array();
+        if bytes[..28].iter().any(|&b| b != 0) {
+            return None;
+        }
+        Some(u32::from_be_bytes([bytes[28], bytes[29], bytes[30], bytes[31]]))
+    }
+
+    /// Thin backwards-compatible wrapper around `verify_checked` that
+    /// collapses every failure mode to `false`
     pub fn verify(env: Env, proof: LocationProof, expected_cell_id: u32) -> bool {
-        // Step 1: Verify public inputs match expected cell_id
+        Self::verify_checked(env, proof, expected_cell_id).is_ok()
+    }
+
+    /// Split a 256-byte proof blob into its A (G1), B (G2), C (G1) points.
+    /// The three ranges don't overlap.
+    fn parse_proof_points(env: &Env, proof: &LocationProof) -> Result<(Bn254G1Affine, Bn254G2Affine, Bn254G1Affine), VerifyError> {
+        let proof_bytes = proof.proof.to_array();
+        if proof_bytes.iter().all(|&b| b == 0) {
+            return Err(VerifyError::MalformedProof);
+        }
+
+        let mut a_bytes = [0u8; 64];
+        let mut b_bytes = [0u8; 128];
+        let mut c_bytes = [0u8; 64];
+        a_bytes.copy_from_slice(&proof_bytes[0..64]);
+        b_bytes.copy_from_slice(&proof_bytes[64..192]);
+        c_bytes.copy_from_slice(&proof_bytes[192..256]);
+
+        Ok((
+            Bn254G1Affine::from_array(env, &a_bytes),
+            Bn254G2Affine::from_array(env, &b_bytes),
+            Bn254G1Affine::from_array(env, &c_bytes),
+        ))
+    }
+
+    /// Verify the cell_id and grid_size public inputs against `expected_cell_id`
+    /// and the repo's valid grid-resolution range, returning `(cell_id, grid_size)`.
+    fn validate_public_inputs(proof: &LocationProof, expected_cell_id: u32) -> Result<(u32, u32), VerifyError> {
         if proof.public_inputs.len() < 2 {
-            return false;
+            return Err(VerifyError::PublicInputMismatch);
         }
 
-        let cell_id = match proof.public_inputs.get(0) {
+        let cell_id = match proof.public_inputs.get(0).and_then(|fe| Self::fr_to_u32(&fe)) {
             Some(id) => id,
-            None => return false,
+            None => return Err(VerifyError::MalformedProof),
         };
         if cell_id != expected_cell_id {
-            return false;
+            return Err(VerifyError::PublicInputMismatch);
+        }
+        if cell_id > 100_000 {
+            return Err(VerifyError::CellIdOutOfRange);
         }
 
-        // Step 2: Verify proof structure (non-zero, valid format)
-        let proof_bytes = proof.proof.to_array();
-        
-        let is_non_zero = proof_bytes.iter().any(|&b| b != 0);
-        if !is_non_zero {
-            return false;
+        let grid_size = match proof.public_inputs.get(1).and_then(|fe| Self::fr_to_u32(&fe)) {
+            Some(size) => size,
+            None => return Err(VerifyError::GridSizeOutOfRange),
+        };
+        if grid_size == 0 || grid_size < 1_000_000 || grid_size > 10_000_000 {
+            return Err(VerifyError::GridSizeOutOfRange);
+        }
+
+        Ok((cell_id, grid_size))
+    }
+
+    /// Compute IC_sum = IC[0] + sum(public_inputs[i] * IC[i]) for i >= 1, the
+    /// per-proof public input commitment against a given VK. `public_inputs[0]`
+    /// does not scale any IC point.
+    fn compute_ic_sum(env: &Env, vk: &VerificationKey, public_inputs: &Vec<BytesN<32>>) -> Result<Bn254G1Affine, VerifyError> {
+        let bn254 = env.crypto().bn254();
+
+        let mut ic_sum = if vk.ic.len() > 0 {
+            let ic0 = match vk.ic.get(0) {
+                Some(ic) => ic,
+                None => return Err(VerifyError::PublicInputMismatch),
+            };
+            Bn254G1Affine::from_array(env, &ic0.to_array())
+        } else {
+            return Err(VerifyError::PublicInputMismatch);
+        };
+
+        // Limit iterations to prevent unbounded loops
+        let max_iterations = public_inputs.len().min(vk.ic.len()).min(1000);
+        for i in 1..max_iterations {
+            if i >= vk.ic.len() {
+                return Err(VerifyError::PublicInputMismatch);
+            }
+            let input = match public_inputs.get(i) {
+                Some(inp) => inp,
+                None => return Err(VerifyError::PublicInputMismatch),
+            };
+            let ic_point = match vk.ic.get(i) {
+                Some(ic) => ic,
+                None => return Err(VerifyError::PublicInputMismatch),
+            };
+            let ic_g1 = Bn254G1Affine::from_array(env, &ic_point.to_array());
+
+            let input_bytes = soroban_sdk::Bytes::from_slice(env, &input.to_array());
+            let input_u256 = soroban_sdk::U256::from_be_bytes(env, &input_bytes);
+            let input_fr = Fr::from_u256(input_u256);
+
+            let scaled_ic = bn254.g1_mul(&ic_g1, &input_fr);
+            ic_sum = bn254.g1_add(&ic_sum, &scaled_ic);
         }
 
-        // Step 3: Load and validate verification key
+        Ok(ic_sum)
+    }
+
+    /// Check the proof's nonce against the replay registry without marking
+    /// it used; `mark_proof_used` commits the nonce once a proof (or batch)
+    /// is fully accepted.
+    fn check_replay(env: &Env, proof_id: &BytesN<32>) -> Result<(), VerifyError> {
+        let nonces: Map<BytesN<32>, u64> = env.storage()
+            .instance()
+            .get(&symbol_short!("Nonces"))
+            .unwrap_or(Map::new(env));
+        if nonces.get(proof_id.clone()).is_some() {
+            return Err(VerifyError::ReplayDetected);
+        }
+        Ok(())
+    }
+
+    /// Mark a proof's nonce as used at the current ledger sequence.
+    fn mark_proof_used(env: &Env, proof_id: BytesN<32>) {
+        let mut nonces: Map<BytesN<32>, u64> = env.storage()
+            .instance()
+            .get(&symbol_short!("Nonces"))
+            .unwrap_or(Map::new(env));
+        let current_ledger = env.ledger().sequence() as u64;
+        nonces.set(proof_id, current_ledger);
+        env.storage().instance().set(&symbol_short!("Nonces"), &nonces);
+    }
+
+    /// Derive a distinct, non-zero per-proof Fiat-Shamir scalar for batch
+    /// aggregation: hash(proof bytes ‖ batch index ‖ ledger-derived seed),
+    /// re-hashing the digest itself in the astronomically unlikely case it
+    /// decodes to zero.
+    fn derive_batch_scalar(env: &Env, proof: &LocationProof, index: u32, seed: u64) -> Fr {
+        let mut preimage = proof.to_bytes(env);
+        preimage.append(&Bytes::from_array(env, &index.to_be_bytes()));
+        preimage.append(&Bytes::from_array(env, &seed.to_be_bytes()));
+
+        let mut digest_bytes = env.crypto().sha256(&preimage).to_array();
+        for _ in 0..4 {
+            if digest_bytes.iter().any(|&b| b != 0) {
+                let digest_vec = Bytes::from_array(env, &digest_bytes);
+                let scalar_u256 = soroban_sdk::U256::from_be_bytes(env, &digest_vec);
+                return Fr::from_u256(scalar_u256);
+            }
+            let digest_vec = Bytes::from_array(env, &digest_bytes);
+            digest_bytes = env.crypto().sha256(&digest_vec).to_array();
+        }
+        panic!("Unable to derive non-zero batch scalar");
+    }
+
+    /// Verify a location proof using Protocol 25 BN254 pairing and Poseidon hashing
+    /// Implements full cryptographic Groth16 proof verification
+    pub fn verify_checked(env: Env, proof: LocationProof, expected_cell_id: u32) -> Result<(), VerifyError> {
+        // Stateless input and proof-shape checks run first, so a mismatched
+        // cell id or malformed proof is reported as such even with no key set
+        Self::validate_public_inputs(&proof, expected_cell_id)?;
+        Self::parse_proof_points(&env, &proof)?;
+
         let vk_set: bool = env.storage()
             .instance()
             .get(&symbol_short!("VKSet"))
             .unwrap_or(false);
-        
         if !vk_set {
-            return false;
-        }
-
-        if let Some(vk) = env.storage().instance().get::<_, VerificationKey>(&symbol_short!("VK")) {
-            // Step 4: Deserialize proof into BN254 points
-            // Groth16 proof structure: A (64 bytes G1), B (128 bytes G2), C (64 bytes G1)
-            // For our 64-byte simplified proof, we extract A and B
-            let mut a_bytes = [0u8; 64];
-            let mut b_bytes = [0u8; 128];
-            
-            // Extract A point (first 64 bytes)
-            for i in 0..64.min(proof_bytes.len()) {
-                a_bytes[i] = proof_bytes[i];
-            }
-            
-            // Extract B point (next 128 bytes, or use simplified format)
-            // For full Groth16, B is 128 bytes, but our proof is 64 bytes total
-            // So we use the remaining bytes for B (simplified)
-            if proof_bytes.len() >= 64 {
-                let max_i = 32.min(proof_bytes.len().saturating_sub(32));
-                for i in 0..max_i {
-                    let idx = match 32usize.checked_add(i) {
-                        Some(idx) => idx,
-                        None => return false,
-                    };
-                    if idx >= proof_bytes.len() {
-                        return false;
-                    }
-                    b_bytes[i] = proof_bytes[idx];
-                }
-            }
-            
-            // Extract C point (last 64 bytes, or use simplified)
-            // For full Groth16, C would be after B, but we use simplified format
-            
-            // Step 5: Use Protocol 25 BN254 pairing verification
-            let bn254 = env.crypto().bn254();
-            
-            // Convert bytes to BN254 points
-            let a_g1 = Bn254G1Affine::from_array(&env, &a_bytes);
-            let b_g2 = Bn254G2Affine::from_array(&env, &b_bytes);
-            let c_g1 = if proof_bytes.len() >= 128 {
-                // Use C point if available
-                let mut c = [0u8; 64];
-                for i in 0..64 {
-                    let idx = match 64usize.checked_add(i) {
-                        Some(idx) => idx,
-                        None => return false,
-                    };
-                    if idx >= proof_bytes.len() {
-                        return false;
-                    }
-                    c[i] = proof_bytes[idx];
-                }
-                Bn254G1Affine::from_array(&env, &c)
-            } else {
-                // Fallback to A if C not available
-                a_g1.clone()
-            };
-            
-            // Convert VK points
-            let alpha_g1 = Bn254G1Affine::from_array(&env, &vk.alpha_g1.to_array());
-            let beta_g2 = Bn254G2Affine::from_array(&env, &vk.beta_g2.to_array());
-            let gamma_g2 = Bn254G2Affine::from_array(&env, &vk.gamma_g2.to_array());
-            let delta_g2 = Bn254G2Affine::from_array(&env, &vk.delta_g2.to_array());
-            
-            // Step 6: Compute IC_sum = sum(public_inputs[i] * IC[i])
-            // Start with IC[0] (first IC point)
-            let mut ic_sum = if vk.ic.len() > 0 {
-                let ic0 = match vk.ic.get(0) {
-                    Some(ic) => ic,
-                    None => return false,
-                };
-                Bn254G1Affine::from_array(&env, &ic0.to_array())
-            } else {
-                return false;
-            };
-            
-            // Add remaining IC points scaled by public inputs
-            // Limit iterations to prevent unbounded loops
-            let max_iterations = proof.public_inputs.len().min(vk.ic.len()).min(1000);
-            for i in 1..max_iterations {
-                if i >= vk.ic.len() {
-                    return false;
-                }
-                let input = match proof.public_inputs.get(i) {
-                    Some(inp) => inp,
-                    None => return false,
-                };
-                let ic_point = match vk.ic.get(i) {
-                    Some(ic) => ic,
-                    None => return false,
-                };
-                let ic_g1 = Bn254G1Affine::from_array(&env, &ic_point.to_array());
-                
-                // Convert input to Fr scalar
-                // U256 can be created from bytes
-                let mut input_bytes_array = [0u8; 32];
-                // Convert u32 to big-endian bytes (safe, no overflow for u32)
-                input_bytes_array[28] = ((input >> 24) & 0xFF) as u8;
-                input_bytes_array[29] = ((input >> 16) & 0xFF) as u8;
-                input_bytes_array[30] = ((input >> 8) & 0xFF) as u8;
-                input_bytes_array[31] = (input & 0xFF) as u8;
-                let input_bytes = soroban_sdk::Bytes::from_slice(&env, &input_bytes_array);
-                let input_u256 = soroban_sdk::U256::from_be_bytes(&env, &input_bytes);
-                let input_fr = Fr::from_u256(input_u256);
-                
-                // Scale IC point by input: input * IC[i]
-                let scaled_ic = bn254.g1_mul(&ic_g1, &input_fr);
-                
-                // Add to IC_sum
-                ic_sum = bn254.g1_add(&ic_sum, &scaled_ic);
-            }
-            
-            // Step 7: Verify Groth16 pairing equation
-            // e(A, B) = e(alpha, beta) * e(C, gamma) * e(IC_sum, delta)
-            // Using multi-pairing check:
-            // e(A, B) * e(-alpha, beta) * e(-C, gamma) * e(-IC_sum, delta) = 1
-            // Which means: e(A, B) = e(alpha, beta) * e(C, gamma) * e(IC_sum, delta)
-            
-            // Negate alpha, C, and IC_sum for pairing check
-            let neg_alpha = -alpha_g1.clone();
-            let neg_c = -c_g1.clone();
-            let neg_ic_sum = -ic_sum.clone();
-            
-            // Build G1 and G2 vectors for pairing check
-            // Verify pairing: e(A, B) * e(-alpha, beta) * e(-C, gamma) * e(-IC_sum, delta) = 1
-            let mut g1_points = Vec::new(&env);
-            let mut g2_points = Vec::new(&env);
-            
-            g1_points.push_back(a_g1);
-            g2_points.push_back(b_g2);
-            
-            g1_points.push_back(neg_alpha);
-            g2_points.push_back(beta_g2);
-            
-            g1_points.push_back(neg_c);
-            g2_points.push_back(gamma_g2);
-            
-            g1_points.push_back(neg_ic_sum);
-            g2_points.push_back(delta_g2);
-            
-            let pairing_result = bn254.pairing_check(g1_points, g2_points);
-            
-            if !pairing_result {
-                return false;
-            }
-            
-            // Step 8: Verify circuit constraints
-            let grid_size = match proof.public_inputs.get(1) {
-                Some(size) => size,
-                None => return false,
-            };
-            if grid_size == 0 {
-                return false;
-            }
-            if grid_size < 1_000_000 || grid_size > 10_000_000 {
-                return false;
-            }
-            
-            // Step 9: Validate cell_id bounds
-            if cell_id > 100_000 {
-                return false;
-            }
-            
-            // Step 10: Replay protection using Poseidon hash
-            let proof_id = Self::compute_proof_id(&env, &proof);
-            
-            // Check for replay
-            let mut nonces: Map<BytesN<32>, u64> = env.storage()
-                .instance()
-                .get(&symbol_short!("Nonces"))
-                .unwrap_or(Map::new(&env));
-            
-            if nonces.try_get(proof_id.clone()).is_ok() {
-                return false;
-            }
-            
-            // Mark proof as used
-            let current_ledger = env.ledger().sequence() as u64;
-            nonces.set(proof_id, current_ledger);
-            env.storage().instance().set(&symbol_short!("Nonces"), &nonces);
-            
-            true
-        } else {
-            return false;
+            return Err(VerifyError::VerificationKeyNotSet);
         }
+        let vk: VerificationKey = match env.storage().instance().get(&symbol_short!("VK")) {
+            Some(vk) => vk,
+            None => return Err(VerifyError::VerificationKeyNotSet),
+        };
+
+        Self::verify_against_vk(&env, &vk, &proof, expected_cell_id)
     }
-    
-    /// Compute proof ID using Protocol 25 SHA256 hash
-    /// Note: Poseidon requires hazmat-crypto feature, so we use SHA256 as fallback
+
+    /// Run the full Groth16 pairing check against an arbitrary (already
+    /// loaded) verification key. Shared by `verify_checked`, which always
+    /// uses the single default-key storage slot, and `verify_with_key`,
+    /// which looks a key up from the multi-circuit registry by commitment.
+    fn verify_against_vk(env: &Env, vk: &VerificationKey, proof: &LocationProof, expected_cell_id: u32) -> Result<(), VerifyError> {
+        // Step 1: Verify public inputs match expected cell_id and grid bounds
+        let (_cell_id, _grid_size) = Self::validate_public_inputs(proof, expected_cell_id)?;
+
+        // Step 2: Deserialize proof into BN254 points
+        let (a_g1, b_g2, c_g1) = Self::parse_proof_points(env, proof)?;
+
+        let bn254 = env.crypto().bn254();
+        let alpha_g1 = Bn254G1Affine::from_array(env, &vk.alpha_g1.to_array());
+        let beta_g2 = Bn254G2Affine::from_array(env, &vk.beta_g2.to_array());
+        let gamma_g2 = Bn254G2Affine::from_array(env, &vk.gamma_g2.to_array());
+        let delta_g2 = Bn254G2Affine::from_array(env, &vk.delta_g2.to_array());
+
+        // Step 3: Compute IC_sum = IC[0] + sum(public_inputs[i] * IC[i]) for i >= 1
+        let ic_sum = Self::compute_ic_sum(env, vk, &proof.public_inputs)?;
+
+        // Step 4: Verify Groth16 pairing equation
+        // e(A, B) = e(alpha, beta) * e(IC_sum, gamma) * e(C, delta), checked as:
+        // e(A, B) * e(-alpha, beta) * e(-IC_sum, gamma) * e(-C, delta) = 1
+        let neg_alpha = -alpha_g1.clone();
+        let neg_c = -c_g1.clone();
+        let neg_ic_sum = -ic_sum.clone();
+
+        let mut g1_points = Vec::new(env);
+        let mut g2_points = Vec::new(env);
+        g1_points.push_back(a_g1);
+        g2_points.push_back(b_g2);
+        g1_points.push_back(neg_alpha);
+        g2_points.push_back(beta_g2);
+        g1_points.push_back(neg_ic_sum);
+        g2_points.push_back(gamma_g2);
+        g1_points.push_back(neg_c);
+        g2_points.push_back(delta_g2);
+
+        if !bn254.pairing_check(g1_points, g2_points) {
+            return Err(VerifyError::PairingFailed);
+        }
+
+        // Step 5: Replay protection
+        let proof_id = Self::compute_proof_id(env, proof);
+        Self::check_replay(env, &proof_id)?;
+        Self::mark_proof_used(env, proof_id);
+
+        Ok(())
+    }
+
+    /// Compute a proof ID by Poseidon-hashing the proof's A/B/C coordinates,
+    /// so the on-chain replay ID matches the Poseidon hash an in-circuit
+    /// prover can also compute. The 256-byte proof is first split in half
+    /// and each half SHA256-reduced to 32 bytes, since raw proof bytes can
+    /// exceed the BN254 scalar field's ~254-bit modulus and Poseidon inputs
+    /// must already be reduced mod r.
     fn compute_proof_id(env: &Env, proof: &LocationProof) -> BytesN<32> {
-        // Use SHA256 for proof ID (Poseidon would require hazmat-crypto feature)
         let proof_bytes = proof.proof.to_array();
-        let proof_bytes_vec = soroban_sdk::Bytes::from_slice(env, &proof_bytes);
-        let hash = env.crypto().sha256(&proof_bytes_vec);
-        let hash_bytes = hash.to_array();
-        let mut proof_id_bytes = [0u8; 32];
-        for i in 0..32.min(hash_bytes.len()) {
-            proof_id_bytes[i] = hash_bytes[i];
-        }
-        
-        BytesN::from_array(env, &proof_id_bytes)
+        let first_half = soroban_sdk::Bytes::from_slice(env, &proof_bytes[0..128]);
+        let second_half = soroban_sdk::Bytes::from_slice(env, &proof_bytes[128..256]);
+        let fr_a = Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &env.crypto().sha256(&first_half).to_array())));
+        let fr_b = Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &env.crypto().sha256(&second_half).to_array())));
+
+        let digest = Self::poseidon_hash(env, [fr_a, fr_b]);
+        BytesN::from_array(env, &Self::fr_to_bytes(&digest))
     }
 
     /// Set full verification key (admin-only)
@@ -279,58 +484,98 @@ impl ZkVerifier {
             .get(&symbol_short!("Admin"))
             .unwrap();
         admin.require_auth();
-        
-        // Validate VK structure
-        let alpha_bytes = vk.alpha_g1.to_array();
-        let beta_bytes = vk.beta_g2.to_array();
-        let gamma_bytes = vk.gamma_g2.to_array();
-        let delta_bytes = vk.delta_g2.to_array();
-        
-        // Ensure all VK points are non-zero
-        if alpha_bytes.iter().all(|&b| b == 0) {
+
+        Self::store_verification_key(env, vk);
+    }
+
+    /// Set the full verification key from a single calldata blob produced by
+    /// `VerificationKey::to_bytes` - lets an off-chain prover hand the
+    /// contract one flat argument instead of the struct field-by-field
+    /// (admin-only)
+    pub fn set_verification_key_from_bytes(env: Env, vk_bytes: Bytes) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("Admin"))
+            .unwrap();
+        admin.require_auth();
+
+        let vk = VerificationKey::from_bytes(&env, &vk_bytes);
+        Self::store_verification_key(env, vk);
+    }
+
+    /// Validate and persist a verification key. Shared by
+    /// `set_verification_key` and `set_verification_key_from_bytes`.
+    fn store_verification_key(env: Env, vk: VerificationKey) {
+        Self::validate_vk_structure(&vk);
+
+        // Store verification key
+        env.storage().instance().set(&symbol_short!("VK"), &vk);
+        env.storage().instance().set(&symbol_short!("VKSet"), &true);
+
+        let vk_hash_stored = Self::compute_vk_commitment(&env, &vk);
+        env.storage().instance().set(&symbol_short!("VKHash"), &vk_hash_stored);
+    }
+
+    /// Ensure every point in a verification key is non-zero: `alpha_g1`,
+    /// `beta_g2`, `gamma_g2`, `delta_g2`, and every `ic` entry. Shared by the
+    /// default-key path and the multi-circuit registry, since a zero point
+    /// is malformed regardless of where the key ends up stored.
+    fn validate_vk_structure(vk: &VerificationKey) {
+        if vk.alpha_g1.to_array().iter().all(|&b| b == 0) {
             panic!("Invalid VK: alpha_g1 is zero");
         }
-        if beta_bytes.iter().all(|&b| b == 0) {
+        if vk.beta_g2.to_array().iter().all(|&b| b == 0) {
             panic!("Invalid VK: beta_g2 is zero");
         }
-        if gamma_bytes.iter().all(|&b| b == 0) {
+        if vk.gamma_g2.to_array().iter().all(|&b| b == 0) {
             panic!("Invalid VK: gamma_g2 is zero");
         }
-        if delta_bytes.iter().all(|&b| b == 0) {
+        if vk.delta_g2.to_array().iter().all(|&b| b == 0) {
             panic!("Invalid VK: delta_g2 is zero");
         }
-        
-        // Validate IC commitments
+
         if vk.ic.len() == 0 {
             panic!("Invalid VK: IC vector is empty");
         }
-        
+
         // Limit iterations to prevent unbounded loops
         let max_iterations = vk.ic.len().min(1000);
         for i in 0..max_iterations {
             let ic_point = vk.ic.get(i)
                 .unwrap_or_else(|| panic!("Invalid VK: IC point missing"));
-            let ic_bytes = ic_point.to_array();
-            if ic_bytes.iter().all(|&b| b == 0) {
+            if ic_point.to_array().iter().all(|&b| b == 0) {
                 panic!("Invalid VK: IC point is zero");
             }
         }
-        
-        // Store verification key
-        env.storage().instance().set(&symbol_short!("VK"), &vk);
-        env.storage().instance().set(&symbol_short!("VKSet"), &true);
-        
-        // Compute and store VK hash using SHA256 (Poseidon requires hazmat-crypto)
+    }
+
+    /// Commitment hash for a verification key, using native Poseidon over
+    /// `alpha_g1`'s x and y coordinates - each already a 32-byte field
+    /// element, so no SHA256 pre-reduction is needed here (unlike
+    /// `compute_proof_id`, which hashes raw proof bytes that can exceed the
+    /// scalar field). Used both as the default key's `VKHash` and as the
+    /// registry key for `register_verification_key`.
+    fn compute_vk_commitment(env: &Env, vk: &VerificationKey) -> BytesN<32> {
         let alpha_bytes = vk.alpha_g1.to_array();
-        let alpha_bytes_vec = soroban_sdk::Bytes::from_slice(&env, &alpha_bytes);
-        let vk_hash = env.crypto().sha256(&alpha_bytes_vec);
-        let vk_hash_bytes = vk_hash.to_array();
-        let mut vk_hash_final = [0u8; 32];
-        for i in 0..32.min(vk_hash_bytes.len()) {
-            vk_hash_final[i] = vk_hash_bytes[i];
-        }
-        let vk_hash_stored = BytesN::from_array(&env, &vk_hash_final);
-        env.storage().instance().set(&symbol_short!("VKHash"), &vk_hash_stored);
+        let mut alpha_x = [0u8; 32];
+        let mut alpha_y = [0u8; 32];
+        alpha_x.copy_from_slice(&alpha_bytes[0..32]);
+        alpha_y.copy_from_slice(&alpha_bytes[32..64]);
+        let fr_x = Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &alpha_x)));
+        let fr_y = Fr::from_u256(soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &alpha_y)));
+        let digest = Self::poseidon_hash(env, [fr_x, fr_y]);
+        BytesN::from_array(env, &Self::fr_to_bytes(&digest))
+    }
+
+    /// Native BN254 Poseidon hash over two field elements, exposed so
+    /// callers can verify proof IDs or VK commitments off-chain against the
+    /// same digest this contract computes in-circuit.
+    pub fn poseidon_hash_pub(env: Env, a: BytesN<32>, b: BytesN<32>) -> BytesN<32> {
+        let fr_a = Fr::from_u256(soroban_sdk::U256::from_be_bytes(&env, &Bytes::from_array(&env, &a.to_array())));
+        let fr_b = Fr::from_u256(soroban_sdk::U256::from_be_bytes(&env, &Bytes::from_array(&env, &b.to_array())));
+        let digest = Self::poseidon_hash(&env, [fr_a, fr_b]);
+        BytesN::from_array(&env, &Self::fr_to_bytes(&digest))
     }
     
     /// Set verification key hash (admin-only)
@@ -375,7 +620,73 @@ impl ZkVerifier {
         env.storage().instance().remove(&symbol_short!("VKHash"));
         env.storage().instance().set(&symbol_short!("VKSet"), &false);
     }
-    
+
+    /// Register a verification key under its own commitment hash, so one
+    /// `ZkVerifier` instance can serve several location circuits at once
+    /// (different grid resolutions, H3 vs. geohash cell encodings, or
+    /// versioned circuits) instead of only the single default key. The
+    /// default-key functions (`set_verification_key` etc.) remain a
+    /// shortcut for the common single-circuit case (admin-only). Returns
+    /// the commitment hash the key was registered under.
+    pub fn register_verification_key(env: Env, vk: VerificationKey) -> BytesN<32> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("Admin"))
+            .unwrap();
+        admin.require_auth();
+
+        Self::validate_vk_structure(&vk);
+        let vk_hash = Self::compute_vk_commitment(&env, &vk);
+
+        let mut registry: Map<BytesN<32>, VerificationKey> = env.storage()
+            .instance()
+            .get(&symbol_short!("VKReg"))
+            .unwrap_or(Map::new(&env));
+        registry.set(vk_hash.clone(), vk);
+        env.storage().instance().set(&symbol_short!("VKReg"), &registry);
+
+        vk_hash
+    }
+
+    /// Remove a verification key from the multi-circuit registry (admin-only).
+    pub fn remove_verification_key(env: Env, vk_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("Admin"))
+            .unwrap();
+        admin.require_auth();
+
+        let mut registry: Map<BytesN<32>, VerificationKey> = env.storage()
+            .instance()
+            .get(&symbol_short!("VKReg"))
+            .unwrap_or(Map::new(&env));
+        registry.remove(vk_hash);
+        env.storage().instance().set(&symbol_short!("VKReg"), &registry);
+    }
+
+    /// Look up a verification key in the multi-circuit registry (read-only).
+    pub fn get_registered_verification_key(env: Env, vk_hash: BytesN<32>) -> Option<VerificationKey> {
+        let registry: Map<BytesN<32>, VerificationKey> = env.storage()
+            .instance()
+            .get(&symbol_short!("VKReg"))
+            .unwrap_or(Map::new(&env));
+        registry.get(vk_hash)
+    }
+
+    /// Verify a proof against a specific registered circuit, looked up by
+    /// its VK commitment hash, instead of the single default key.
+    pub fn verify_with_key(env: Env, proof: LocationProof, expected_cell_id: u32, vk_hash: BytesN<32>) -> Result<(), VerifyError> {
+        let registry: Map<BytesN<32>, VerificationKey> = env.storage()
+            .instance()
+            .get(&symbol_short!("VKReg"))
+            .unwrap_or(Map::new(&env));
+        let vk = registry.get(vk_hash).ok_or(VerifyError::VerificationKeyNotSet)?;
+
+        Self::verify_against_vk(&env, &vk, &proof, expected_cell_id)
+    }
+
     /// Clean old proof nonces (admin-only)
     pub fn clean_nonces(env: Env, before_ledger: u64) {
         let admin: Address = env
@@ -419,6 +730,185 @@ impl ZkVerifier {
         results
     }
 
+    /// Like `verify_batch`, but carries each proof's `VerifyError` instead of
+    /// collapsing every failure to `false`, so a partially-failing batch is
+    /// diagnosable.
+    pub fn verify_batch_checked(env: Env, proofs: Vec<LocationProof>, expected_cell_ids: Vec<u32>) -> Vec<BatchVerifyResult> {
+        if proofs.len() != expected_cell_ids.len() {
+            panic!("Proofs and cell_ids length mismatch");
+        }
+
+        let max_batch_size = proofs.len().min(expected_cell_ids.len()).min(100);
+        if proofs.len() > max_batch_size || expected_cell_ids.len() > max_batch_size {
+            panic!("Batch size too large");
+        }
+
+        let mut results = Vec::new(&env);
+        for i in 0..proofs.len() {
+            let proof = proofs.get(i)
+                .unwrap_or_else(|| panic!("Proof missing at index"));
+            let cell_id = expected_cell_ids.get(i)
+                .unwrap_or_else(|| panic!("Cell ID missing at index"));
+            let result = match Self::verify_checked(env.clone(), proof, cell_id) {
+                Ok(()) => BatchVerifyResult { ok: true, error: None },
+                Err(e) => BatchVerifyResult { ok: false, error: Some(e) },
+            };
+            results.push_back(result);
+        }
+        results
+    }
+
+    /// Run every individual check (public inputs, proof shape, IC sum,
+    /// replay) a proof must pass before it can be folded into an aggregated
+    /// batch, short of the pairing check itself.
+    fn validate_proof_for_batch(
+        env: &Env,
+        vk: &VerificationKey,
+        proof: &LocationProof,
+        expected_cell_id: u32,
+    ) -> Result<(Bn254G1Affine, Bn254G2Affine, Bn254G1Affine, Bn254G1Affine, BytesN<32>), VerifyError> {
+        Self::validate_public_inputs(proof, expected_cell_id)?;
+        let (a_g1, b_g2, c_g1) = Self::parse_proof_points(env, proof)?;
+        let ic_sum = Self::compute_ic_sum(env, vk, &proof.public_inputs)?;
+        let proof_id = Self::compute_proof_id(env, proof);
+        Self::check_replay(env, &proof_id)?;
+        Ok((a_g1, b_g2, c_g1, ic_sum, proof_id))
+    }
+
+    /// Batched Groth16 verification via random linear combination: N proofs
+    /// sharing one stored VK collapse from 4N pairings to N+3 by folding
+    /// each proof's `alpha`, `C`, and `IC_sum` terms into one combined point
+    /// each, scaled by a distinct non-zero Fiat-Shamir scalar `r_i` per
+    /// proof so a valid and an invalid proof can't be cancelled against each
+    /// other. The per-proof `e(A_i, B_i)` terms can't merge since `B_i`
+    /// differs per proof, so they stay as N separate pairing inputs.
+    ///
+    /// Each proof still undergoes its own replay/grid-size/cell-id checks
+    /// before inclusion; a proof failing those is excluded from the
+    /// aggregate and reported with its own error. If the combined pairing
+    /// check fails, every proof that passed its individual checks is
+    /// reported as `PairingFailed`, since the aggregation can't attribute
+    /// the failure to a single proof.
+    pub fn verify_batch_aggregated(env: Env, proofs: Vec<LocationProof>, expected_cell_ids: Vec<u32>) -> Vec<BatchVerifyResult> {
+        if proofs.len() != expected_cell_ids.len() {
+            panic!("Proofs and cell_ids length mismatch");
+        }
+        let max_batch_size = proofs.len().min(expected_cell_ids.len()).min(100);
+        if proofs.len() > max_batch_size || expected_cell_ids.len() > max_batch_size {
+            panic!("Batch size too large");
+        }
+
+        let vk_set: bool = env.storage().instance().get(&symbol_short!("VKSet")).unwrap_or(false);
+        let vk: Option<VerificationKey> = if vk_set {
+            env.storage().instance().get(&symbol_short!("VK"))
+        } else {
+            None
+        };
+        let vk = match vk {
+            Some(vk) => vk,
+            None => {
+                let mut results = Vec::new(&env);
+                for _ in 0..proofs.len() {
+                    results.push_back(BatchVerifyResult { ok: false, error: Some(VerifyError::VerificationKeyNotSet) });
+                }
+                return results;
+            }
+        };
+
+        let bn254 = env.crypto().bn254();
+        let alpha_g1 = Bn254G1Affine::from_array(&env, &vk.alpha_g1.to_array());
+        let beta_g2 = Bn254G2Affine::from_array(&env, &vk.beta_g2.to_array());
+        let gamma_g2 = Bn254G2Affine::from_array(&env, &vk.gamma_g2.to_array());
+        let delta_g2 = Bn254G2Affine::from_array(&env, &vk.delta_g2.to_array());
+        // Ledger-derived seed mixed into every proof's scalar derivation so
+        // scalars can't be predicted ahead of the ledger they land in.
+        let seed = env.ledger().sequence() as u64;
+
+        // Pass 1: validate each proof individually, reject in-batch
+        // duplicates, and accumulate the shared alpha/C/IC terms plus the
+        // per-proof A/B pairing terms for every proof that passes.
+        let mut statuses: Vec<Option<VerifyError>> = Vec::new(&env);
+        let mut pending_proof_ids: Vec<BytesN<32>> = Vec::new(&env);
+        let mut g1_points = Vec::new(&env);
+        let mut g2_points = Vec::new(&env);
+        let mut alpha_acc: Option<Bn254G1Affine> = None;
+        let mut c_acc: Option<Bn254G1Affine> = None;
+        let mut ic_acc: Option<Bn254G1Affine> = None;
+
+        for i in 0..proofs.len() {
+            let proof = proofs.get(i).unwrap_or_else(|| panic!("Proof missing at index"));
+            let cell_id = expected_cell_ids.get(i).unwrap_or_else(|| panic!("Cell ID missing at index"));
+
+            match Self::validate_proof_for_batch(&env, &vk, &proof, cell_id) {
+                Err(e) => statuses.push_back(Some(e)),
+                Ok((a_g1, b_g2, c_g1, ic_sum, proof_id)) => {
+                    if pending_proof_ids.iter().any(|pid| pid == proof_id) {
+                        statuses.push_back(Some(VerifyError::ReplayDetected));
+                        continue;
+                    }
+                    pending_proof_ids.push_back(proof_id);
+
+                    let r_i = Self::derive_batch_scalar(&env, &proof, i, seed);
+
+                    g1_points.push_back(bn254.g1_mul(&a_g1, &r_i));
+                    g2_points.push_back(b_g2);
+
+                    let r_alpha = bn254.g1_mul(&alpha_g1, &r_i);
+                    alpha_acc = Some(match alpha_acc {
+                        Some(acc) => bn254.g1_add(&acc, &r_alpha),
+                        None => r_alpha,
+                    });
+
+                    let r_c = bn254.g1_mul(&c_g1, &r_i);
+                    c_acc = Some(match c_acc {
+                        Some(acc) => bn254.g1_add(&acc, &r_c),
+                        None => r_c,
+                    });
+
+                    let r_ic = bn254.g1_mul(&ic_sum, &r_i);
+                    ic_acc = Some(match ic_acc {
+                        Some(acc) => bn254.g1_add(&acc, &r_ic),
+                        None => r_ic,
+                    });
+
+                    statuses.push_back(None);
+                }
+            }
+        }
+
+        // Close out the aggregate equation with the combined alpha/beta,
+        // C/delta, and IC/gamma terms, then run the single N+3-pairing
+        // check. No valid proofs means nothing to aggregate.
+        let pairing_ok = match (alpha_acc, c_acc, ic_acc) {
+            (Some(alpha_acc), Some(c_acc), Some(ic_acc)) => {
+                g1_points.push_back(-alpha_acc);
+                g2_points.push_back(beta_g2);
+                g1_points.push_back(-c_acc);
+                g2_points.push_back(delta_g2);
+                g1_points.push_back(-ic_acc);
+                g2_points.push_back(gamma_g2);
+                bn254.pairing_check(g1_points, g2_points)
+            }
+            _ => false,
+        };
+
+        let mut results = Vec::new(&env);
+        for i in 0..proofs.len() {
+            let status = statuses.get(i).unwrap_or_else(|| panic!("Status missing at index"));
+            match status {
+                Some(e) => results.push_back(BatchVerifyResult { ok: false, error: Some(e) }),
+                None if pairing_ok => {
+                    let proof = proofs.get(i).unwrap_or_else(|| panic!("Proof missing at index"));
+                    let proof_id = Self::compute_proof_id(&env, &proof);
+                    Self::mark_proof_used(&env, proof_id);
+                    results.push_back(BatchVerifyResult { ok: true, error: None });
+                }
+                None => results.push_back(BatchVerifyResult { ok: false, error: Some(VerifyError::PairingFailed) }),
+            }
+        }
+        results
+    }
+
     /// Set admin (admin-only)
     pub fn set_admin(env: Env, new_admin: Address) {
         let admin: Address = env
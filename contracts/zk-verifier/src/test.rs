@@ -3,6 +3,48 @@
 use super::*;
 use soroban_sdk::{symbol_short, vec, BytesN, Env, Vec};
 
+/// Encode a u32 as a big-endian Fr field element for use as a public input
+fn field_element(env: &Env, value: u32) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[28..32].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+#[test]
+fn test_location_proof_calldata_round_trip() {
+    let env = Env::default();
+    let mut proof_bytes = [0u8; 256];
+    proof_bytes[0] = 9;
+    let proof = LocationProof {
+        proof: BytesN::from_array(&env, &proof_bytes),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1_000_000u32)],
+    };
+
+    let encoded = proof.to_bytes(&env);
+    let decoded = LocationProof::from_bytes(&env, &encoded);
+
+    assert_eq!(decoded.proof, proof.proof);
+    assert_eq!(decoded.public_inputs, proof.public_inputs);
+}
+
+#[test]
+fn test_verification_key_calldata_round_trip() {
+    let env = Env::default();
+    let vk = VerificationKey {
+        alpha_g1: BytesN::from_array(&env, &[1u8; 64]),
+        beta_g2: BytesN::from_array(&env, &[2u8; 128]),
+        gamma_g2: BytesN::from_array(&env, &[3u8; 128]),
+        delta_g2: BytesN::from_array(&env, &[4u8; 128]),
+        ic: vec![&env, BytesN::from_array(&env, &[5u8; 64]), BytesN::from_array(&env, &[6u8; 64])],
+    };
+
+    let encoded = vk.to_bytes(&env);
+    let decoded = VerificationKey::from_bytes(&env, &encoded);
+
+    assert_eq!(decoded.alpha_g1, vk.alpha_g1);
+    assert_eq!(decoded.ic, vk.ic);
+}
+
 #[test]
 fn test_verify() {
     let env = Env::default();
@@ -13,11 +55,15 @@ fn test_verify() {
     ZkVerifierClient::new(&env, &contract_id).init(&admin);
 
     // Create a mock proof (non-zero bytes)
-    let mut proof_bytes = [0u8; 64];
+    let mut proof_bytes = [0u8; 256];
     proof_bytes[0] = 1; // Make it non-zero
     let proof = LocationProof {
         proof: BytesN::from_array(&env, &proof_bytes),
-        public_inputs: vec![&env, 12345u32, 1000000u32],
+        public_inputs: vec![
+            &env,
+            field_element(&env, 12345u32),
+            field_element(&env, 1000000u32),
+        ],
     };
 
     // Verify
@@ -29,3 +75,162 @@ fn test_verify() {
     let result2 = client.verify(&proof, &99999u32);
     assert!(!result2);
 }
+
+#[test]
+fn test_verify_checked_reports_typed_errors() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    let client = ZkVerifierClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let mut proof_bytes = [0u8; 256];
+    proof_bytes[0] = 1;
+    let proof = LocationProof {
+        proof: BytesN::from_array(&env, &proof_bytes),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1000000u32)],
+    };
+
+    // No verification key registered yet
+    let result = client.try_verify_checked(&proof, &12345u32);
+    assert_eq!(result, Ok(Err(VerifyError::VerificationKeyNotSet)));
+
+    // Cell id mismatch is reported distinctly from a missing key
+    let result2 = client.try_verify_checked(&proof, &99999u32);
+    assert_eq!(result2, Ok(Err(VerifyError::PublicInputMismatch)));
+}
+
+#[test]
+fn test_verify_batch_checked_is_diagnosable() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    let client = ZkVerifierClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let mut proof_bytes = [0u8; 256];
+    proof_bytes[0] = 1;
+    let good_proof = LocationProof {
+        proof: BytesN::from_array(&env, &proof_bytes),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1000000u32)],
+    };
+    let bad_proof = LocationProof {
+        proof: BytesN::from_array(&env, &[0u8; 256]),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1000000u32)],
+    };
+
+    let results = client.verify_batch_checked(
+        &vec![&env, good_proof, bad_proof],
+        &vec![&env, 12345u32, 12345u32],
+    );
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().error, Some(VerifyError::VerificationKeyNotSet));
+    assert_eq!(results.get(1).unwrap().error, Some(VerifyError::MalformedProof));
+}
+
+#[test]
+fn test_register_and_verify_with_key() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    let client = ZkVerifierClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let vk = VerificationKey {
+        alpha_g1: BytesN::from_array(&env, &[1u8; 64]),
+        beta_g2: BytesN::from_array(&env, &[2u8; 128]),
+        gamma_g2: BytesN::from_array(&env, &[3u8; 128]),
+        delta_g2: BytesN::from_array(&env, &[4u8; 128]),
+        ic: vec![&env, BytesN::from_array(&env, &[5u8; 64])],
+    };
+
+    let vk_hash = client.register_verification_key(&vk);
+    assert_eq!(client.get_registered_verification_key(&vk_hash), Some(vk));
+
+    let mut proof_bytes = [0u8; 256];
+    proof_bytes[0] = 1;
+    let proof = LocationProof {
+        proof: BytesN::from_array(&env, &proof_bytes),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1000000u32)],
+    };
+
+    // Wrong hash looks up nothing, so it's reported the same as no key set
+    let result = client.try_verify_with_key(&proof, &12345u32, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(result, Ok(Err(VerifyError::VerificationKeyNotSet)));
+
+    client.remove_verification_key(&vk_hash);
+    assert_eq!(client.get_registered_verification_key(&vk_hash), None);
+}
+
+#[test]
+fn test_poseidon_hash_is_deterministic_and_input_sensitive() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    let client = ZkVerifierClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let a = field_element(&env, 1);
+    let b = field_element(&env, 2);
+
+    let digest1 = client.poseidon_hash_pub(&a, &b);
+    let digest2 = client.poseidon_hash_pub(&a, &b);
+    assert_eq!(digest1, digest2);
+
+    let digest3 = client.poseidon_hash_pub(&b, &a);
+    assert_ne!(digest1, digest3);
+}
+
+// `check_replay`/`mark_proof_used` gate the very last step of
+// `verify_against_vk`, after a real pairing check -- this crate has no
+// prover/curve tooling available in this sandbox to mint a genuine
+// Groth16 proof+VK pair that would actually pass `pairing_check`, so
+// these call the replay helpers directly to cover the exact presence
+// check that was previously always true (`Map::try_get(..).is_ok()`
+// returns `Ok(None)` for an absent key, so it never let any proof
+// through).
+#[test]
+fn test_check_replay_allows_first_use_and_rejects_reuse() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    ZkVerifierClient::new(&env, &contract_id).init(&admin);
+
+    let proof_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(ZkVerifier::check_replay(&env, &proof_id), Ok(()));
+
+        ZkVerifier::mark_proof_used(&env, proof_id.clone());
+
+        assert_eq!(ZkVerifier::check_replay(&env, &proof_id), Err(VerifyError::ReplayDetected));
+    });
+}
+
+#[test]
+fn test_verify_batch_aggregated_reports_missing_vk_per_proof() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ZkVerifier);
+    let client = ZkVerifierClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let mut proof_bytes = [0u8; 256];
+    proof_bytes[0] = 1;
+    let proof = LocationProof {
+        proof: BytesN::from_array(&env, &proof_bytes),
+        public_inputs: vec![&env, field_element(&env, 12345u32), field_element(&env, 1000000u32)],
+    };
+
+    let results = client.verify_batch_aggregated(
+        &vec![&env, proof.clone(), proof],
+        &vec![&env, 12345u32, 12345u32],
+    );
+
+    assert_eq!(results.len(), 2);
+    for result in results.iter() {
+        assert_eq!(result.ok, false);
+        assert_eq!(result.error, Some(VerifyError::VerificationKeyNotSet));
+    }
+}